@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+
+use crate::hardware::disk::DiskOptions;
+use crate::print_module::logo::LogoChoice;
+use crate::print_module::theme::{ColorMode, Theme, ThemeOverrides};
+use crate::print_module::{LABELS, OutputFormat, TextOptions, Units};
+use crate::report::Module;
+
+pub static DEFAULT_CONFIG: &str = r#"# atlasfetch configuration
+#
+# Place this file at $XDG_CONFIG_HOME/atlasfetch/config.toml
+# (~/.config/atlasfetch/config.toml by default) or pass it with --config.
+# Command-line flags take precedence over the values below.
+
+# Sections to display, in order.
+# Available: "distro", "board", "packages", "desktop", "battery", "gpu",
+# "display", "memory", "cpu", "disk"
+# modules = [
+#     "distro", "board", "packages", "desktop", "battery", "gpu", "display",
+#     "memory", "cpu", "disk",
+# ]
+
+# Sections to leave out. Added to the --skip flag; ignored when --modules
+# is given on the command line.
+# skip = []
+
+# Output format: "text" or "json".
+# format = "text"
+
+# Size units: "binary" (GiB, KiB) or "decimal" (GB, kB).
+# units = "binary"
+
+# Logo shown next to the report: "auto" (from /etc/os-release), "none",
+# or one of "arch", "debian", "ubuntu", "fedora", "manjaro", "linuxmint",
+# "opensuse", "gentoo", "nixos", "void", "alpine", "tux".
+# logo = "auto"
+
+# Path to a text file with a custom logo. Overrides `logo`. A leading ~ is
+# expanded to $HOME.
+# logo_file = "~/.config/atlasfetch/logo.txt"
+
+# When to use ANSI colours: "auto" (only on a terminal and when NO_COLOR
+# is unset), "always" or "never".
+# color = "auto"
+
+# Colour theme: "default", "mono", "ocean", "forest", "sunset".
+# theme = "default"
+
+# Maximum output width in columns. Detected from the terminal by default.
+# width = 100
+
+# Break the memory section down into buffers, page cache, shmem, slab,
+# dirty/writeback pages, huge pages and committed memory.
+# memory_detail = false
+
+# Replacement labels for the text output.
+[labels]
+# os = "OS"
+# kernel = "Kernel"
+# uptime = "Uptime"
+# os_installed = "OS Installed"
+# shell = "Shell"
+# host = "Host"
+# board = "Board"
+# bios = "BIOS"
+# packages = "Packages"
+# desktop = "DE"
+# window_manager = "WM"
+# session = "Session"
+# battery = "Battery"
+# gpu = "GPU"
+# display = "Display"
+# ram = "RAM"
+# memory_slots = "Slots"
+# swap = "Swap"
+# zram = "Zram"
+# zswap = "Zswap"
+# buffers = "Buffers"
+# cached = "Cached"
+# shmem = "Shmem"
+# slab = "Slab"
+# dirty = "Dirty"
+# anon_huge_pages = "AnonHugePages"
+# huge_pages = "HugeTLB"
+# committed = "Committed"
+# cpu_model = "CPU Model"
+# cpu_cache = "CPU Cache"
+# disk = "Disk"
+# disk_io = "I/O"
+# mounts = "Mounts"
+
+# Per-element colour overrides on top of the theme. Accepts "default",
+# "accent" (the distro colour), "black", "red", "green", "yellow", "blue",
+# "magenta", "cyan", "white", their "bright_" variants, or a 256-colour
+# palette index such as "208".
+[colors]
+# border = "accent"
+# label = "accent"
+# value = "default"
+# logo = "accent"
+
+# Disk section settings.
+[disk]
+# Also list loop, ram, zram and device-mapper block devices.
+# show_virtual = false
+# List every mount, including pseudo filesystems, loops and bind mounts.
+# show_all_mounts = false
+# Sample /proc/diskstats over this many milliseconds and show per-disk
+# throughput, IOPS, latency, queue depth and utilisation.
+# io_sample_ms = 1000
+
+# Mount rules are checked in order before the built-in ones (hide loop
+# devices, squashfs and overlay, show zfs). The first rule whose fs_type,
+# mount_point and source all match decides; globs accept * and ?.
+# [[disk.mount_rules]]
+# fs_type = "tmpfs"
+# mount_point = "/tmp"
+# action = "show"
+#
+# [[disk.mount_rules]]
+# mount_point = "/var/lib/docker/*"
+# action = "hide"
+"#;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub modules: Option<Vec<Module>>,
+    pub skip: Vec<Module>,
+    pub format: Option<OutputFormat>,
+    pub units: Option<Units>,
+    pub logo: Option<String>,
+    pub logo_file: Option<PathBuf>,
+    pub color: Option<ColorMode>,
+    pub theme: Option<String>,
+    pub width: Option<usize>,
+    pub memory_detail: bool,
+    pub labels: HashMap<String, String>,
+    pub colors: ThemeOverrides,
+    pub disk: DiskOptions,
+}
+
+impl Config {
+    pub fn load(path: Option<&Path>) -> Result<Self> {
+        if let Some(path) = path {
+            return Self::from_file(path);
+        }
+
+        match Self::default_path() {
+            Some(path) if path.exists() => Self::from_file(&path),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path).with_context(|| {
+            format!(
+                "config: Config::from_file() - Error reading {}",
+                path.display()
+            )
+        })?;
+
+        let config: Self = toml::from_str(&content).with_context(|| {
+            format!(
+                "config: Config::from_file() - Error parsing {}",
+                path.display()
+            )
+        })?;
+
+        config.check_labels().with_context(|| {
+            format!(
+                "config: Config::from_file() - Invalid [labels] in {}",
+                path.display()
+            )
+        })?;
+
+        Ok(config)
+    }
+
+    fn check_labels(&self) -> Result<()> {
+        if let Some(key) = self
+            .labels
+            .keys()
+            .find(|key| !LABELS.contains(&key.as_str()))
+        {
+            bail!(
+                "config: Config::check_labels() - Unknown label \"{}\" (available: {})",
+                key,
+                LABELS.join(", ")
+            );
+        }
+
+        Ok(())
+    }
+
+    pub fn default_path() -> Option<PathBuf> {
+        let config_home = match env::var_os("XDG_CONFIG_HOME") {
+            Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+            _ => PathBuf::from(env::var_os("HOME")?).join(".config"),
+        };
+
+        Some(config_home.join("atlasfetch").join("config.toml"))
+    }
+
+    pub fn text_options(
+        &self,
+        logo: Option<LogoChoice>,
+        theme: Option<&str>,
+    ) -> Result<TextOptions> {
+        let logo = match (logo, &self.logo_file, &self.logo) {
+            (Some(logo), _, _) => logo,
+            (None, Some(path), _) => LogoChoice::from_file(path)?,
+            (None, None, Some(name)) => LogoChoice::from_name(name)?,
+            (None, None, None) => LogoChoice::Auto,
+        };
+
+        Ok(TextOptions {
+            labels: self.labels.clone(),
+            units: self.units.unwrap_or_default(),
+            logo,
+            color: self.color.unwrap_or_default(),
+            theme: self.theme(theme.or(self.theme.as_deref()))?,
+            width: self.width,
+            memory_detail: self.memory_detail,
+        })
+    }
+
+    pub fn theme(&self, name: Option<&str>) -> Result<Theme> {
+        let mut theme = match name {
+            Some(name) => Theme::from_name(name)?,
+            None => Theme::default(),
+        };
+
+        theme.apply(&self.colors);
+
+        Ok(theme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_config_parses() {
+        let config: Config = toml::from_str(DEFAULT_CONFIG).unwrap();
+
+        assert!(config.check_labels().is_ok());
+    }
+
+    #[test]
+    fn rejects_unknown_label() {
+        let config: Config = toml::from_str("[labels]\nkernel = \"Linux\"\n").unwrap();
+        assert!(config.check_labels().is_ok());
+
+        let config: Config = toml::from_str("[labels]\nkernal = \"Linux\"\n").unwrap();
+        let error = config.check_labels().unwrap_err().to_string();
+        assert!(error.contains("\"kernal\""));
+    }
+}
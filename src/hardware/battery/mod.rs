@@ -1,20 +1,41 @@
 use std::fs;
 
 use anyhow::{Context, Result};
+use serde::Serialize;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub enum BatteryStatus {
     Charging,
     Discharging,
     Unknow,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+pub enum CapacityUnit {
+    WattHours,
+    AmpHours,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatteryCapacity {
+    pub full: f64,
+    pub design: f64,
+    pub unit: CapacityUnit,
+}
+
+#[derive(Debug, Serialize)]
 pub struct BatteryInfo {
     pub power_level: u8,
     pub status: BatteryStatus,
     pub bat_name: String,
     pub model: String,
+    pub manufacturer: Option<String>,
+    pub technology: Option<String>,
+    pub serial_number: Option<String>,
+    pub cycle_count: Option<u32>,
+    pub voltage: Option<f64>,
+    pub capacity: Option<BatteryCapacity>,
+    pub health: Option<f64>,
 }
 
 impl BatteryInfo {
@@ -22,12 +43,24 @@ impl BatteryInfo {
         let power_level = Self::get_capacity(path)?;
         let status = Self::get_status(path)?;
         let model = Self::get_model(path)?;
+        let capacity = Self::get_capacity_info(path);
 
         Ok(Self {
             power_level,
             status,
             bat_name,
             model,
+            manufacturer: read_value(path, "manufacturer"),
+            technology: read_value(path, "technology"),
+            serial_number: read_value(path, "serial_number"),
+            cycle_count: read_value(path, "cycle_count")
+                .and_then(|count| count.parse::<u32>().ok())
+                .filter(|count| *count > 0),
+            voltage: read_value(path, "voltage_now")
+                .and_then(|voltage| voltage.parse::<u64>().ok())
+                .map(|microvolts| microvolts as f64 / 1_000_000.0),
+            health: capacity.as_ref().and_then(BatteryCapacity::health),
+            capacity,
         })
     }
 
@@ -73,10 +106,10 @@ impl BatteryInfo {
         let power_level = fs::read_to_string(&path)
             .context("battery::BatteryInfo::get_capacity(): Error reading capacity-file")?;
 
-        Ok(power_level
+        power_level
             .trim()
             .parse::<u8>()
-            .context("battery::BatteryInfo::get_capacity(): Error parsing power_level")?)
+            .context("battery::BatteryInfo::get_capacity(): Error parsing power_level")
     }
 
     fn get_status(start_path: &str) -> Result<BatteryStatus> {
@@ -100,4 +133,38 @@ impl BatteryInfo {
 
         Ok(model_name.trim().to_string())
     }
+
+    fn get_capacity_info(start_path: &str) -> Option<BatteryCapacity> {
+        [
+            ("energy", CapacityUnit::WattHours),
+            ("charge", CapacityUnit::AmpHours),
+        ]
+        .into_iter()
+        .find_map(|(prefix, unit)| {
+            let read = |file: &str| {
+                read_value(start_path, &format!("{}_{}", prefix, file))?
+                    .parse::<u64>()
+                    .ok()
+            };
+
+            Some(BatteryCapacity {
+                full: read("full")? as f64 / 1_000_000.0,
+                design: read("full_design")? as f64 / 1_000_000.0,
+                unit,
+            })
+        })
+    }
+}
+
+impl BatteryCapacity {
+    pub fn health(&self) -> Option<f64> {
+        (self.design > 0.0).then(|| (self.full / self.design * 1000.0).round() / 10.0)
+    }
+}
+
+fn read_value(start_path: &str, file: &str) -> Option<String> {
+    let value = fs::read_to_string(format!("{}/{}", start_path, file)).ok()?;
+    let value = value.trim();
+
+    (!value.is_empty()).then(|| value.to_string())
 }
@@ -0,0 +1,112 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Result, bail};
+use serde::Serialize;
+
+use crate::hardware::smbios::clean_string;
+
+#[derive(Debug, Serialize)]
+pub struct BoardInfo {
+    pub sys_vendor: Option<String>,
+    pub product_name: Option<String>,
+    pub product_version: Option<String>,
+    pub product_family: Option<String>,
+    pub board_vendor: Option<String>,
+    pub board_name: Option<String>,
+    pub board_version: Option<String>,
+    pub bios_vendor: Option<String>,
+    pub bios_version: Option<String>,
+    pub bios_date: Option<String>,
+    pub chassis_vendor: Option<String>,
+    pub chassis_type: Option<String>,
+}
+
+impl BoardInfo {
+    pub fn new() -> Result<Self> {
+        let path = Path::new("/sys/class/dmi/id");
+
+        if !path.exists() {
+            bail!(
+                "board: BoardInfo::new() - {} does not exist",
+                path.display()
+            );
+        }
+
+        let read = |attribute: &str| {
+            fs::read_to_string(path.join(attribute))
+                .ok()
+                .and_then(|value| clean_string(&value))
+        };
+
+        let board_info = Self {
+            sys_vendor: read("sys_vendor"),
+            product_name: read("product_name"),
+            product_version: read("product_version"),
+            product_family: read("product_family"),
+            board_vendor: read("board_vendor"),
+            board_name: read("board_name"),
+            board_version: read("board_version"),
+            bios_vendor: read("bios_vendor"),
+            bios_version: read("bios_version"),
+            bios_date: read("bios_date"),
+            chassis_vendor: read("chassis_vendor"),
+            chassis_type: read("chassis_type")
+                .and_then(|value| value.parse::<u8>().ok())
+                .and_then(chassis_type)
+                .map(String::from),
+        };
+
+        if board_info.product_name.is_none()
+            && board_info.board_name.is_none()
+            && board_info.bios_version.is_none()
+        {
+            bail!("board: BoardInfo::new() - No DMI identification available");
+        }
+
+        Ok(board_info)
+    }
+}
+
+fn chassis_type(value: u8) -> Option<&'static str> {
+    let name = match value & 0x7f {
+        0x01 => "Other",
+        0x03 => "Desktop",
+        0x04 => "Low Profile Desktop",
+        0x05 => "Pizza Box",
+        0x06 => "Mini Tower",
+        0x07 => "Tower",
+        0x08 => "Portable",
+        0x09 => "Laptop",
+        0x0a => "Notebook",
+        0x0b => "Hand Held",
+        0x0c => "Docking Station",
+        0x0d => "All in One",
+        0x0e => "Sub Notebook",
+        0x0f => "Space-saving",
+        0x10 => "Lunch Box",
+        0x11 => "Main Server Chassis",
+        0x12 => "Expansion Chassis",
+        0x13 => "SubChassis",
+        0x14 => "Bus Expansion Chassis",
+        0x15 => "Peripheral Chassis",
+        0x16 => "RAID Chassis",
+        0x17 => "Rack Mount Chassis",
+        0x18 => "Sealed-case PC",
+        0x19 => "Multi-system Chassis",
+        0x1a => "Compact PCI",
+        0x1b => "Advanced TCA",
+        0x1c => "Blade",
+        0x1d => "Blade Enclosure",
+        0x1e => "Tablet",
+        0x1f => "Convertible",
+        0x20 => "Detachable",
+        0x21 => "IoT Gateway",
+        0x22 => "Embedded PC",
+        0x23 => "Mini PC",
+        0x24 => "Stick PC",
+        _ => return None,
+    };
+
+    Some(name)
+}
@@ -3,8 +3,9 @@ use std::fs::File;
 use std::io::Read;
 
 use anyhow::{Context, Result};
+use serde::Serialize;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub enum CacheType {
     Data,
     Instruction,
@@ -12,14 +13,14 @@ pub enum CacheType {
     Unknow,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct CacheInfo {
     pub size: u32,
     pub level: u8,
     pub cache_type: CacheType,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct CpuInfo {
     pub model_name: String,
     pub siblings_count: u8,
@@ -42,11 +43,11 @@ impl CacheInfo {
         let cache_size =
             fs::read_to_string(&path).context("cpu: get_cache_size - error reading file")?;
 
-        Ok(cache_size
+        cache_size
             .replace("K", "")
             .trim()
             .parse::<u32>()
-            .context("cpu: get_cache_size - error parsing to u32")?)
+            .context("cpu: get_cache_size - error parsing to u32")
     }
 
     fn get_cache_level(start_path: &str) -> Result<u8> {
@@ -55,10 +56,10 @@ impl CacheInfo {
         let cache_level_string =
             fs::read_to_string(&path).context("cpu: get_cache_level - error reading file")?;
 
-        Ok(cache_level_string
+        cache_level_string
             .trim()
             .parse::<u8>()
-            .context("cpu: get_cache_level - error parsing to u8")?)
+            .context("cpu: get_cache_level - error parsing to u8")
     }
 
     fn get_cache_type(start_path: &str) -> Result<CacheType> {
@@ -144,9 +145,9 @@ impl CpuInfo {
 
         let mut model_name: String = String::new();
 
-        for i in 0..(information_split.len()) {
-            if information_split[i].contains("model name") {
-                let model_split: Vec<&str> = information_split[i].split(":").collect();
+        for line in information_split {
+            if line.contains("model name") {
+                let model_split: Vec<&str> = line.split(":").collect();
 
                 model_name = String::from(model_split[1].trim());
                 break;
@@ -161,18 +162,18 @@ impl CpuInfo {
 
         let mut siblings: String = String::new();
 
-        for i in 0..(information_split.len()) {
-            if information_split[i].contains("siblings") {
-                let siblings_split: Vec<&str> = information_split[i].split(":").collect();
+        for line in information_split {
+            if line.contains("siblings") {
+                let siblings_split: Vec<&str> = line.split(":").collect();
 
                 siblings = String::from(siblings_split[1].trim());
                 break;
             }
         }
 
-        Ok(siblings
+        siblings
             .parse::<u8>()
-            .context("cpu: get_siblings - error parsing to u8")?)
+            .context("cpu: get_siblings - error parsing to u8")
     }
 
     fn get_frequency() -> Option<f32> {
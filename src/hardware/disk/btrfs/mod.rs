@@ -0,0 +1,116 @@
+use std::fs;
+use std::path::Path;
+
+use serde::Serialize;
+
+use super::{read_attribute, read_sectors};
+
+#[derive(Debug, Serialize)]
+pub struct BtrfsAllocation {
+    pub profiles: Vec<String>,
+    pub total_bytes: u64,
+    pub used_bytes: u64,
+    pub disk_total_bytes: u64,
+    pub disk_used_bytes: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BtrfsInfo {
+    pub uuid: String,
+    pub label: Option<String>,
+    pub data: BtrfsAllocation,
+    pub metadata: BtrfsAllocation,
+    pub system: BtrfsAllocation,
+    pub devices: Vec<String>,
+    pub device_size_bytes: u64,
+    pub unallocated_bytes: u64,
+    pub free_estimate_bytes: u64,
+}
+
+impl BtrfsInfo {
+    pub fn for_device(device: &str) -> Option<Self> {
+        fs::read_dir("/sys/fs/btrfs")
+            .ok()?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .find(|path| list_devices(path).iter().any(|name| name == device))
+            .and_then(|path| BtrfsInfo::from_sysfs(&path))
+    }
+
+    fn from_sysfs(path: &Path) -> Option<Self> {
+        let allocation = path.join("allocation");
+
+        let data = BtrfsAllocation::from_sysfs(&allocation.join("data"))?;
+        let metadata = BtrfsAllocation::from_sysfs(&allocation.join("metadata"))?;
+        let system = BtrfsAllocation::from_sysfs(&allocation.join("system"))?;
+
+        let mut devices = list_devices(path);
+        devices.sort();
+
+        let device_size_bytes: u64 = devices
+            .iter()
+            .filter_map(|name| read_sectors(&path.join("devices").join(name)).ok())
+            .sum();
+
+        let unallocated_bytes = device_size_bytes.saturating_sub(
+            data.disk_total_bytes + metadata.disk_total_bytes + system.disk_total_bytes,
+        );
+
+        let data_ratio = match data.total_bytes {
+            0 => 1.0,
+            total => (data.disk_total_bytes as f64 / total as f64).max(1.0),
+        };
+
+        let free_estimate_bytes = data.total_bytes.saturating_sub(data.used_bytes)
+            + (unallocated_bytes as f64 / data_ratio) as u64;
+
+        Some(Self {
+            uuid: path.file_name()?.to_str()?.to_string(),
+            label: read_attribute(path, "label"),
+            data,
+            metadata,
+            system,
+            devices,
+            device_size_bytes,
+            unallocated_bytes,
+            free_estimate_bytes,
+        })
+    }
+}
+
+impl BtrfsAllocation {
+    fn from_sysfs(path: &Path) -> Option<Self> {
+        let read = |attribute: &str| read_attribute(path, attribute)?.parse::<u64>().ok();
+
+        let mut profiles: Vec<String> = fs::read_dir(path)
+            .ok()?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().join("total_bytes").exists())
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .map(|profile| match profile.as_str() {
+                "single" => profile,
+                _ => profile.to_uppercase(),
+            })
+            .collect();
+        profiles.sort();
+
+        Some(Self {
+            profiles,
+            total_bytes: read("total_bytes")?,
+            used_bytes: read("bytes_used")?,
+            disk_total_bytes: read("disk_total").unwrap_or(0),
+            disk_used_bytes: read("disk_used").unwrap_or(0),
+        })
+    }
+}
+
+fn list_devices(path: &Path) -> Vec<String> {
+    fs::read_dir(path.join("devices"))
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
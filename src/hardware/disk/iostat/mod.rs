@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::fs;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy)]
+struct Counters {
+    reads: u64,
+    read_sectors: u64,
+    read_ms: u64,
+    writes: u64,
+    write_sectors: u64,
+    write_ms: u64,
+    io_ms: u64,
+    weighted_ms: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DiskIo {
+    pub name: String,
+    pub read_bytes_per_sec: f64,
+    pub write_bytes_per_sec: f64,
+    pub read_iops: f64,
+    pub write_iops: f64,
+    pub read_latency_ms: Option<f64>,
+    pub write_latency_ms: Option<f64>,
+    pub queue_depth: f64,
+    pub utilization: f64,
+}
+
+pub fn sample(names: &[&str], interval: Duration) -> Result<Vec<DiskIo>> {
+    let before = read_diskstats()?;
+    let start = Instant::now();
+
+    thread::sleep(interval);
+
+    let after = read_diskstats()?;
+    let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    Ok(names
+        .iter()
+        .filter_map(|name| {
+            let before = before.get(*name)?;
+            let after = after.get(*name)?;
+
+            Some(DiskIo::from_counters(name, before, after, elapsed_ms))
+        })
+        .collect())
+}
+
+impl DiskIo {
+    fn from_counters(name: &str, before: &Counters, after: &Counters, elapsed_ms: f64) -> Self {
+        let delta = |field: fn(&Counters) -> u64| field(after).saturating_sub(field(before)) as f64;
+        let seconds = elapsed_ms / 1000.0;
+
+        let reads = delta(|c| c.reads);
+        let writes = delta(|c| c.writes);
+
+        let latency = |time: f64, count: f64| (count > 0.0).then(|| time / count);
+
+        Self {
+            name: name.to_string(),
+            read_bytes_per_sec: delta(|c| c.read_sectors) * 512.0 / seconds,
+            write_bytes_per_sec: delta(|c| c.write_sectors) * 512.0 / seconds,
+            read_iops: reads / seconds,
+            write_iops: writes / seconds,
+            read_latency_ms: latency(delta(|c| c.read_ms), reads),
+            write_latency_ms: latency(delta(|c| c.write_ms), writes),
+            queue_depth: delta(|c| c.weighted_ms) / elapsed_ms,
+            utilization: (delta(|c| c.io_ms) / elapsed_ms * 100.0).min(100.0),
+        }
+    }
+}
+
+fn read_diskstats() -> Result<HashMap<String, Counters>> {
+    let diskstats = fs::read_to_string("/proc/diskstats")
+        .context("disk: iostat::read_diskstats() - Error reading /proc/diskstats")?;
+
+    Ok(diskstats.lines().filter_map(parse_line).collect())
+}
+
+fn parse_line(line: &str) -> Option<(String, Counters)> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let number = |index: usize| fields.get(index)?.parse::<u64>().ok();
+
+    Some((
+        fields.get(2)?.to_string(),
+        Counters {
+            reads: number(3)?,
+            read_sectors: number(5)?,
+            read_ms: number(6)?,
+            writes: number(7)?,
+            write_sectors: number(9)?,
+            write_ms: number(10)?,
+            io_ms: number(12)?,
+            weighted_ms: number(13)?,
+        },
+    ))
+}
@@ -1,119 +1,359 @@
+pub mod btrfs;
+pub mod iostat;
+pub mod mountinfo;
+pub mod rules;
+pub mod tree;
+pub mod zfs;
+
+use std::ffi::CString;
 use std::fs;
+use std::io;
+use std::num::NonZeroU64;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+use serde::{Deserialize, Serialize};
+
+use btrfs::BtrfsInfo;
+use iostat::DiskIo;
+use mountinfo::MountEntry;
+use rules::MountRule;
+use tree::{BlockNode, Filesystem, TreeContext};
+use zfs::ZfsPool;
+
+static VIRTUAL_PREFIXES: [&str; 4] = ["loop", "ram", "zram", "dm-"];
+static PARAVIRTUAL_DRIVERS: [&str; 2] = ["virtio_blk", "vbd"];
+static PARAVIRTUAL_PREFIXES: [&str; 2] = ["vd", "xvd"];
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DiskOptions {
+    pub show_virtual: bool,
+    pub show_all_mounts: bool,
+    pub mount_rules: Vec<MountRule>,
+    pub io_sample_ms: Option<NonZeroU64>,
+}
 
-use sys_info;
-use sysinfo::Disks;
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum DiskKind {
+    Nvme,
+    Ssd,
+    Hdd,
+    Mmc,
+    Paravirtual,
+    Virtual,
+    Unknow,
+}
 
-use anyhow::{Context, Result};
+#[derive(Debug, Serialize)]
+pub struct PhysicalDisk {
+    pub name: String,
+    pub model: Option<String>,
+    pub vendor: Option<String>,
+    pub serial: Option<String>,
+    pub size_bytes: u64,
+    pub kind: DiskKind,
+    pub removable: bool,
+    pub read_only: bool,
+    pub filesystem: Filesystem,
+    pub children: Vec<BlockNode>,
+}
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct PartitionInfo {
     pub name: String,
-    pub size: f64,
     pub mount_point: String,
     pub file_system: String,
+    pub total_bytes: u64,
+    pub used_bytes: u64,
+    pub available_bytes: u64,
+    pub used_percent: u8,
+    pub inodes_total: u64,
+    pub inodes_used: u64,
+    pub mount_options: Vec<String>,
+    pub other_mount_points: Vec<String>,
+    pub btrfs: Option<BtrfsInfo>,
+    pub zfs: Option<ZfsPool>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct DiskInfo {
-    pub total_size: f64,
-    pub free_size: f64,
-    pub mount_point: String,
+    pub disks: Vec<PhysicalDisk>,
     pub partitions: Vec<PartitionInfo>,
+    pub io: Option<Vec<DiskIo>>,
 }
 
 impl DiskInfo {
-    pub fn new() -> Result<Self> {
-        let total_size: f64;
-        let free_size: f64;
-        let mount_point = Self::get_mount_point()?;
-        let partitions = PartitionInfo::build()?;
-
-        match sys_info::disk_info().context("DiskInfo::new()") {
-            Ok(diskinfo) => {
-                total_size = parse_to_gb(diskinfo.total, false);
-                free_size = parse_to_gb(diskinfo.free, false);
+    pub fn new(options: &DiskOptions) -> Result<Self> {
+        let mounts = mountinfo::read()?;
+        let disks = PhysicalDisk::get_disks(options, &mounts)?;
+        let partitions = PartitionInfo::build(options, &mounts);
+
+        let io = options.io_sample_ms.and_then(|interval| {
+            let names: Vec<&str> = disks.iter().map(|disk| disk.name.as_str()).collect();
+
+            iostat::sample(&names, Duration::from_millis(interval.get())).ok()
+        });
+
+        Ok(Self {
+            disks,
+            partitions,
+            io,
+        })
+    }
+}
+
+impl PhysicalDisk {
+    pub fn get_disks(options: &DiskOptions, mounts: &[MountEntry]) -> Result<Vec<PhysicalDisk>> {
+        let context = TreeContext::new(mounts);
+
+        let entries = fs::read_dir("/sys/block/")
+            .context("disk: PhysicalDisk::get_disks() - Error reading directory")?;
+
+        let mut disks: Vec<PhysicalDisk> = Vec::new();
+
+        for entry in entries {
+            let entry = entry.context("disk: PhysicalDisk::get_disks() - DirEntry error")?;
+
+            let Ok(name) = entry.file_name().into_string() else {
+                continue;
+            };
+
+            let Ok(disk) = PhysicalDisk::from_block(&entry.path(), name, &context) else {
+                continue;
+            };
+
+            if disk.size_bytes == 0 || (disk.kind == DiskKind::Virtual && !options.show_virtual) {
+                continue;
             }
-            Err(error) => return Err(error),
+
+            disks.push(disk);
+        }
+
+        if disks.is_empty() {
+            bail!("disk: PhysicalDisk::get_disks() - No block devices found in /sys/block");
+        }
+
+        disks.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(disks)
+    }
+
+    fn from_block(block_path: &Path, name: String, context: &TreeContext) -> Result<Self> {
+        let size_bytes = read_sectors(block_path).with_context(|| {
+            format!(
+                "disk: PhysicalDisk::from_block() - Error reading size of {}",
+                name
+            )
+        })?;
+
+        let is_virtual = VIRTUAL_PREFIXES
+            .iter()
+            .any(|prefix| name.starts_with(prefix))
+            || fs::canonicalize(block_path)
+                .is_ok_and(|path| path.starts_with("/sys/devices/virtual"));
+
+        let kind = match read_attribute(block_path, "queue/rotational").as_deref() {
+            _ if is_virtual => DiskKind::Virtual,
+            _ if name.starts_with("nvme") => DiskKind::Nvme,
+            _ if name.starts_with("mmcblk") => DiskKind::Mmc,
+            _ if is_paravirtual(block_path, &name) => DiskKind::Paravirtual,
+            Some("1") => DiskKind::Hdd,
+            Some("0") => DiskKind::Ssd,
+            _ => DiskKind::Unknow,
         };
 
         Ok(Self {
-            total_size,
-            free_size,
-            mount_point,
-            partitions,
+            model: read_attribute(block_path, "device/model"),
+            vendor: read_attribute(block_path, "device/vendor")
+                .filter(|vendor| vendor != "ATA" && !vendor.starts_with("0x")),
+            serial: get_serial(block_path),
+            size_bytes,
+            kind,
+            removable: read_attribute(block_path, "removable").as_deref() == Some("1"),
+            read_only: read_attribute(block_path, "ro").as_deref() == Some("1"),
+            filesystem: context.filesystem(block_path, &name),
+            children: tree::children(block_path, context),
+            name,
         })
     }
+}
+
+fn get_serial(block_path: &Path) -> Option<String> {
+    if let Some(serial) =
+        read_attribute(block_path, "device/serial").or_else(|| read_attribute(block_path, "serial"))
+    {
+        return Some(serial);
+    }
+
+    if let Ok(page) = fs::read(block_path.join("device/vpd_pg80"))
+        && page.len() > 4
+    {
+        let serial = String::from_utf8_lossy(&page[4..]).trim().to_string();
+
+        if !serial.is_empty() {
+            return Some(serial);
+        }
+    }
+
+    let dev = read_attribute(block_path, "dev")?;
+    let udev = fs::read_to_string(format!("/run/udev/data/b{}", dev)).ok()?;
+
+    udev.lines()
+        .find_map(|line| line.strip_prefix("E:ID_SERIAL_SHORT="))
+        .map(String::from)
+}
 
-    fn get_mount_point() -> Result<String> {
-        let file = fs::read_to_string("/proc/partitions")
-            .context("disk::DiskInfo::get_mount_point() - Error reading file")?;
+fn read_sectors(path: &Path) -> Result<u64> {
+    let sectors = fs::read_to_string(path.join("size"))?;
 
-        let parsed_file = file
-            .lines()
-            .nth(2)
-            .context("disk::DiskInfo::get_mount_point() - Error parsing line")?;
+    Ok(sectors.trim().parse::<u64>()? * 512)
+}
 
-        let parsed_line = parsed_file
-            .split_whitespace()
-            .nth(3)
-            .context("disk::DiskInfo::get_mount_point() - Error parsing line")?;
+fn is_paravirtual(block_path: &Path, name: &str) -> bool {
+    let driver = fs::read_link(block_path.join("device/driver"))
+        .ok()
+        .and_then(|driver| driver.file_name()?.to_str().map(String::from));
 
-        Ok(parsed_line.to_string())
+    match driver {
+        Some(driver) => PARAVIRTUAL_DRIVERS.contains(&driver.as_str()),
+        None => PARAVIRTUAL_PREFIXES
+            .iter()
+            .any(|prefix| name.starts_with(prefix)),
     }
 }
 
+fn read_attribute(path: &Path, attribute: &str) -> Option<String> {
+    let value = fs::read_to_string(path.join(attribute)).ok()?;
+    let value = value.trim();
+
+    (!value.is_empty()).then(|| value.to_string())
+}
+
 impl PartitionInfo {
-    fn new(name: String, size: f64, mount_point: String, file_system: String) -> Self {
-        Self {
-            name,
-            size,
-            mount_point,
-            file_system,
+    pub fn build(options: &DiskOptions, mounts: &[MountEntry]) -> Vec<PartitionInfo> {
+        if options.show_all_mounts {
+            return mounts
+                .iter()
+                .filter_map(|mount| PartitionInfo::from_mount(mount).ok())
+                .collect();
+        }
+
+        let mut groups: Vec<Vec<&MountEntry>> = Vec::new();
+
+        for mount in mounts
+            .iter()
+            .filter(|mount| rules::is_visible(mount, &options.mount_rules))
+        {
+            match groups
+                .iter_mut()
+                .find(|group| group[0].device == mount.device)
+            {
+                Some(group) => group.push(mount),
+                None => groups.push(vec![mount]),
+            }
         }
+
+        groups
+            .into_iter()
+            .filter_map(|group| {
+                let primary = group
+                    .iter()
+                    .min_by_key(|mount| (mount.root != "/", mount.mount_point.len()))?;
+
+                let mut partition = PartitionInfo::from_mount(primary).ok()?;
+                partition.other_mount_points = group
+                    .iter()
+                    .filter(|mount| mount.mount_point != primary.mount_point)
+                    .map(|mount| mount.mount_point.clone())
+                    .collect();
+
+                Some(partition)
+            })
+            .collect()
     }
 
-    pub fn build() -> Result<Vec<PartitionInfo>> {
-        let mut returned_vec: Vec<PartitionInfo> = Vec::new();
-
-        let disk_list = Disks::new_with_refreshed_list();
-
-        for disk in disk_list.list() {
-            let name = disk
-                .name()
-                .to_str()
-                .context("disk::PartitionInfo::build() - name")?
-                .to_string();
-            let size = parse_to_gb(disk.available_space(), true);
-            let mount_point = disk
-                .mount_point()
-                .to_str()
-                .context("disk::PartitionInfo::build() - mount_point")?
-                .to_string();
-            let file_system = disk
-                .file_system()
-                .to_str()
-                .context("disk::PartitionInfo::build() - file_system")?
-                .to_string();
-
-            returned_vec.push(Self::new(name, size, mount_point, file_system))
+    fn from_mount(mount: &MountEntry) -> Result<Self> {
+        let stat = statvfs(&mount.mount_point).with_context(|| {
+            format!(
+                "disk: PartitionInfo::from_mount() - statvfs failed for {}",
+                mount.mount_point
+            )
+        })?;
+
+        let block_size = stat.f_frsize as u64;
+        let total_bytes = stat.f_blocks as u64 * block_size;
+        let free_bytes = stat.f_bfree as u64 * block_size;
+        let available_bytes = stat.f_bavail as u64 * block_size;
+        let used_bytes = total_bytes.saturating_sub(free_bytes);
+
+        let used_percent = match used_bytes + available_bytes {
+            0 => 0,
+            usable => (used_bytes * 100).div_ceil(usable).min(100) as u8,
+        };
+
+        let mut mount_options: Vec<String> = mount
+            .mount_options
+            .split(',')
+            .filter(|option| !option.is_empty())
+            .map(String::from)
+            .collect();
+
+        for option in mount.super_options.split(',') {
+            if !option.is_empty()
+                && option != "rw"
+                && option != "ro"
+                && !mount_options.iter().any(|known| known == option)
+            {
+                mount_options.push(option.to_string());
+            }
         }
 
-        Ok(returned_vec)
+        Ok(Self {
+            name: mount.source.clone(),
+            mount_point: mount.mount_point.clone(),
+            file_system: mount.fs_type.clone(),
+            total_bytes,
+            used_bytes,
+            available_bytes,
+            used_percent,
+            inodes_total: stat.f_files as u64,
+            inodes_used: (stat.f_files as u64).saturating_sub(stat.f_ffree as u64),
+            mount_options,
+            other_mount_points: Vec::new(),
+            btrfs: match mount.fs_type.as_str() {
+                "btrfs" => fs::canonicalize(&mount.source)
+                    .ok()
+                    .and_then(|source| BtrfsInfo::for_device(source.file_name()?.to_str()?)),
+                _ => None,
+            },
+            zfs: match mount.fs_type.as_str() {
+                "zfs" => ZfsPool::for_dataset(&mount.source),
+                _ => None,
+            },
+        })
+    }
+
+    pub fn inodes_percent(&self) -> Option<u8> {
+        match self.inodes_total {
+            0 => None,
+            total => Some((self.inodes_used * 100).div_ceil(total).min(100) as u8),
+        }
     }
 }
 
-fn parse_to_gb(data: u64, bytes: bool) -> f64 {
-    let mb_total = if bytes {
-        data as f64 / 1024.0 / 1024.0
-    } else {
-        data as f64 / 1024.0
-    };
+fn statvfs(path: &str) -> Result<libc::statvfs> {
+    let path = CString::new(path)?;
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
 
-    let gb = (mb_total / 1024.0).trunc() as u64;
+    let result = unsafe { libc::statvfs(path.as_ptr(), &mut stat) };
 
-    let mb_fraction = (mb_total - (gb as f64 * 1024.0)) / 1024.0;
+    if result != 0 {
+        return Err(io::Error::last_os_error().into());
+    }
 
-    (gb as f64) + (mb_fraction * 100.0).trunc() / 100.0
+    Ok(stat)
 }
 
 /*
@@ -164,3 +404,35 @@ fn parse_line_partitions(line: &str) -> (String, f64) {
 
 
 */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paravirtual_disks() {
+        let root = std::env::temp_dir().join(format!("atlasfetch-block-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        for (name, driver) in [
+            ("vda", "virtio_blk"),
+            ("xvda", "vbd"),
+            ("vdb", "sd"),
+            ("sda", "sd"),
+        ] {
+            let device = root.join(name).join("device");
+            fs::create_dir_all(&device).unwrap();
+            std::os::unix::fs::symlink(root.join("drivers").join(driver), device.join("driver"))
+                .unwrap();
+        }
+        fs::create_dir_all(root.join("vdc")).unwrap();
+
+        assert!(is_paravirtual(&root.join("vda"), "vda"));
+        assert!(is_paravirtual(&root.join("xvda"), "xvda"));
+        assert!(!is_paravirtual(&root.join("vdb"), "vdb"));
+        assert!(!is_paravirtual(&root.join("sda"), "sda"));
+        assert!(is_paravirtual(&root.join("vdc"), "vdc"));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,61 @@
+use std::fs;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MountEntry {
+    pub device: String,
+    pub root: String,
+    pub mount_point: String,
+    pub mount_options: String,
+    pub fs_type: String,
+    pub source: String,
+    pub super_options: String,
+}
+
+pub fn read() -> Result<Vec<MountEntry>> {
+    let mountinfo = fs::read_to_string("/proc/self/mountinfo")
+        .context("disk: mountinfo::read() - Error reading /proc/self/mountinfo")?;
+
+    Ok(mountinfo.lines().filter_map(parse_line).collect())
+}
+
+fn parse_line(line: &str) -> Option<MountEntry> {
+    let (mount, filesystem) = line.split_once(" - ")?;
+
+    let mount: Vec<&str> = mount.split(' ').collect();
+    let mut filesystem = filesystem.split(' ');
+
+    Some(MountEntry {
+        device: mount.get(2)?.to_string(),
+        root: unescape(mount.get(3)?),
+        mount_point: unescape(mount.get(4)?),
+        mount_options: mount.get(5)?.to_string(),
+        fs_type: filesystem.next()?.to_string(),
+        source: unescape(filesystem.next()?),
+        super_options: filesystem.next().unwrap_or_default().to_string(),
+    })
+}
+
+fn unescape(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut unescaped: Vec<u8> = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'\\'
+            && let Some(octal) = bytes.get(i + 1..i + 4)
+            && let Ok(byte) = u8::from_str_radix(&String::from_utf8_lossy(octal), 8)
+        {
+            unescaped.push(byte);
+            i += 4;
+            continue;
+        }
+
+        unescaped.push(bytes[i]);
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&unescaped).into_owned()
+}
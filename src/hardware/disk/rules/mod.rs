@@ -0,0 +1,180 @@
+use serde::Deserialize;
+
+use super::mountinfo::MountEntry;
+
+static NETWORK_FILESYSTEMS: [&str; 7] = [
+    "nfs",
+    "nfs4",
+    "cifs",
+    "smb3",
+    "fuse.sshfs",
+    "ceph",
+    "glusterfs",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MountAction {
+    Show,
+    Hide,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MountRule {
+    pub fs_type: Option<String>,
+    pub mount_point: Option<String>,
+    pub source: Option<String>,
+    pub action: MountAction,
+}
+
+impl MountRule {
+    fn new(fs_type: Option<&str>, source: Option<&str>, action: MountAction) -> Self {
+        Self {
+            fs_type: fs_type.map(String::from),
+            mount_point: None,
+            source: source.map(String::from),
+            action,
+        }
+    }
+
+    pub fn defaults() -> Vec<MountRule> {
+        vec![
+            Self::new(None, Some("/dev/loop*"), MountAction::Hide),
+            Self::new(Some("squashfs"), None, MountAction::Hide),
+            Self::new(Some("overlay"), None, MountAction::Hide),
+            Self::new(Some("zfs"), None, MountAction::Show),
+        ]
+    }
+
+    fn matches(&self, mount: &MountEntry) -> bool {
+        let fs_type = self
+            .fs_type
+            .as_ref()
+            .is_none_or(|fs_type| fs_type == &mount.fs_type);
+        let mount_point = self
+            .mount_point
+            .as_ref()
+            .is_none_or(|pattern| glob_match(pattern, &mount.mount_point));
+        let source = self
+            .source
+            .as_ref()
+            .is_none_or(|pattern| glob_match(pattern, &mount.source));
+
+        fs_type && mount_point && source
+    }
+}
+
+pub fn is_visible(mount: &MountEntry, rules: &[MountRule]) -> bool {
+    let rule = rules
+        .iter()
+        .chain(&MountRule::defaults())
+        .find(|rule| rule.matches(mount))
+        .map(|rule| rule.action);
+
+    match rule {
+        Some(action) => action == MountAction::Show,
+        None => {
+            mount.source.starts_with("/dev/")
+                || NETWORK_FILESYSTEMS.contains(&mount.fs_type.as_str())
+        }
+    }
+}
+
+pub fn glob_match(pattern: &str, value: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let value: Vec<char> = value.chars().collect();
+
+    let (mut p, mut v) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while v < value.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, v));
+                p += 1;
+            }
+            Some('?') => {
+                p += 1;
+                v += 1;
+            }
+            Some(c) if *c == value[v] => {
+                p += 1;
+                v += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    v = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mount(fs_type: &str, source: &str, mount_point: &str) -> MountEntry {
+        MountEntry {
+            device: "0:42".to_string(),
+            root: "/".to_string(),
+            mount_point: mount_point.to_string(),
+            mount_options: "rw,relatime".to_string(),
+            fs_type: fs_type.to_string(),
+            source: source.to_string(),
+            super_options: "rw".to_string(),
+        }
+    }
+
+    #[test]
+    fn default_visibility() {
+        assert!(is_visible(&mount("ext4", "/dev/nvme0n1p2", "/"), &[]));
+        assert!(is_visible(&mount("nfs4", "nas:/export", "/mnt/nas"), &[]));
+        assert!(is_visible(&mount("zfs", "rpool/ROOT/ubuntu", "/"), &[]));
+        assert!(!is_visible(
+            &mount("squashfs", "/dev/loop3", "/snap/core/1"),
+            &[]
+        ));
+        assert!(!is_visible(
+            &mount("overlay", "overlay", "/var/lib/docker/x"),
+            &[]
+        ));
+        assert!(!is_visible(&mount("tmpfs", "tmpfs", "/tmp"), &[]));
+    }
+
+    #[test]
+    fn user_rules_come_first() {
+        let rules = vec![
+            MountRule {
+                fs_type: Some("zfs".to_string()),
+                mount_point: Some("/var/lib/docker/*".to_string()),
+                source: None,
+                action: MountAction::Hide,
+            },
+            MountRule::new(Some("tmpfs"), None, MountAction::Show),
+        ];
+
+        assert!(!is_visible(
+            &mount("zfs", "rpool/docker/abc", "/var/lib/docker/abc"),
+            &rules
+        ));
+        assert!(is_visible(&mount("zfs", "rpool/home", "/home"), &rules));
+        assert!(is_visible(&mount("tmpfs", "tmpfs", "/tmp"), &rules));
+    }
+
+    #[test]
+    fn glob_patterns() {
+        assert!(glob_match("/dev/loop*", "/dev/loop12"));
+        assert!(glob_match("/dev/sd?1", "/dev/sda1"));
+        assert!(glob_match("*docker*", "/var/lib/docker/overlay2"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("/dev/sd?1", "/dev/sda2"));
+        assert!(!glob_match("/dev/loop*", "/dev/sda"));
+    }
+}
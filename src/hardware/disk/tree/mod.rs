@@ -0,0 +1,240 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde::Serialize;
+
+use super::mountinfo::MountEntry;
+use super::{read_attribute, read_sectors};
+
+static MAX_DEPTH: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum NodeKind {
+    Partition,
+    Crypt,
+    Lvm,
+    Raid,
+    Dm,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct Filesystem {
+    pub fs_type: Option<String>,
+    pub uuid: Option<String>,
+    pub label: Option<String>,
+    pub mount_points: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BlockNode {
+    pub name: String,
+    pub mapper_name: Option<String>,
+    pub kind: NodeKind,
+    pub size_bytes: u64,
+    pub slaves: Vec<String>,
+    pub filesystem: Filesystem,
+    pub children: Vec<BlockNode>,
+}
+
+pub struct TreeContext<'a> {
+    uuids: HashMap<String, String>,
+    labels: HashMap<String, String>,
+    mounts: &'a [MountEntry],
+}
+
+impl<'a> TreeContext<'a> {
+    pub fn new(mounts: &'a [MountEntry]) -> Self {
+        Self {
+            uuids: read_links("/dev/disk/by-uuid"),
+            labels: read_links("/dev/disk/by-label"),
+            mounts,
+        }
+    }
+
+    pub fn filesystem(&self, device_path: &Path, name: &str) -> Filesystem {
+        let device = read_attribute(device_path, "dev");
+        let udev = device
+            .as_ref()
+            .and_then(|device| fs::read_to_string(format!("/run/udev/data/b{}", device)).ok())
+            .unwrap_or_default();
+
+        let udev_value = |key: &str| {
+            udev.lines()
+                .find_map(|line| {
+                    line.strip_prefix("E:")?
+                        .strip_prefix(key)?
+                        .strip_prefix('=')
+                })
+                .map(String::from)
+        };
+
+        let mounts: Vec<&MountEntry> = self
+            .mounts
+            .iter()
+            .filter(|mount| {
+                Some(&mount.device) == device.as_ref()
+                    || mount.source.starts_with("/dev/")
+                        && fs::canonicalize(&mount.source)
+                            .is_ok_and(|source| source.file_name().is_some_and(|file| file == name))
+            })
+            .collect();
+
+        let mut mount_points: Vec<String> = Vec::new();
+
+        for mount in &mounts {
+            if !mount_points.contains(&mount.mount_point) {
+                mount_points.push(mount.mount_point.clone());
+            }
+        }
+
+        Filesystem {
+            fs_type: udev_value("ID_FS_TYPE")
+                .or_else(|| mounts.first().map(|mount| mount.fs_type.clone())),
+            uuid: self
+                .uuids
+                .get(name)
+                .cloned()
+                .or_else(|| udev_value("ID_FS_UUID")),
+            label: self
+                .labels
+                .get(name)
+                .cloned()
+                .or_else(|| udev_value("ID_FS_LABEL")),
+            mount_points,
+        }
+    }
+}
+
+pub fn children(block_path: &Path, context: &TreeContext) -> Vec<BlockNode> {
+    let mut partitions: Vec<(u32, BlockNode)> = fs::read_dir(block_path)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| {
+                    let path = entry.path();
+                    let number = read_attribute(&path, "partition")?.parse().ok()?;
+                    let name = entry.file_name().into_string().ok()?;
+
+                    Some((
+                        number,
+                        build_node(&path, name, NodeKind::Partition, context, 0),
+                    ))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    partitions.sort_by_key(|(number, _)| *number);
+
+    let mut nodes: Vec<BlockNode> = partitions.into_iter().map(|(_, node)| node).collect();
+    nodes.extend(holders(block_path, context, 0));
+
+    nodes
+}
+
+fn holders(device_path: &Path, context: &TreeContext, depth: usize) -> Vec<BlockNode> {
+    if depth >= MAX_DEPTH {
+        return Vec::new();
+    }
+
+    let mut names: Vec<String> = list_dir(&device_path.join("holders"));
+    names.sort();
+
+    names
+        .into_iter()
+        .map(|name| {
+            let holder_path = Path::new("/sys/block").join(&name);
+            let kind = holder_kind(&holder_path, &name);
+
+            build_node(&holder_path, name, kind, context, depth + 1)
+        })
+        .collect()
+}
+
+fn build_node(
+    device_path: &Path,
+    name: String,
+    kind: NodeKind,
+    context: &TreeContext,
+    depth: usize,
+) -> BlockNode {
+    let mut slaves = match kind {
+        NodeKind::Partition => Vec::new(),
+        _ => list_dir(&device_path.join("slaves")),
+    };
+    slaves.sort();
+
+    BlockNode {
+        mapper_name: read_attribute(device_path, "dm/name"),
+        kind,
+        size_bytes: read_sectors(device_path).unwrap_or(0),
+        slaves,
+        filesystem: context.filesystem(device_path, &name),
+        children: holders(device_path, context, depth),
+        name,
+    }
+}
+
+fn holder_kind(holder_path: &Path, name: &str) -> NodeKind {
+    if name.starts_with("md") {
+        return NodeKind::Raid;
+    }
+
+    match read_attribute(holder_path, "dm/uuid") {
+        Some(uuid) if uuid.starts_with("CRYPT-") => NodeKind::Crypt,
+        Some(uuid) if uuid.starts_with("LVM-") => NodeKind::Lvm,
+        _ => NodeKind::Dm,
+    }
+}
+
+fn list_dir(path: &Path) -> Vec<String> {
+    fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn read_links(directory: &str) -> HashMap<String, String> {
+    let Ok(entries) = fs::read_dir(directory) else {
+        return HashMap::new();
+    };
+
+    entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let target = fs::canonicalize(entry.path()).ok()?;
+            let device = target.file_name()?.to_str()?.to_string();
+            let value = decode_udev(entry.file_name().to_str()?);
+
+            Some((device, value))
+        })
+        .collect()
+}
+
+fn decode_udev(value: &str) -> String {
+    let mut decoded: Vec<u8> = Vec::with_capacity(value.len());
+    let bytes = value.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'\\'
+            && bytes.get(i + 1) == Some(&b'x')
+            && let Some(hex) = bytes.get(i + 2..i + 4)
+            && let Ok(byte) = u8::from_str_radix(&String::from_utf8_lossy(hex), 16)
+        {
+            decoded.push(byte);
+            i += 4;
+            continue;
+        }
+
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
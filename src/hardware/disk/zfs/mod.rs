@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+pub struct ZfsDataset {
+    pub name: String,
+    pub reads: u64,
+    pub writes: u64,
+    pub read_bytes: u64,
+    pub written_bytes: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ZfsPool {
+    pub name: String,
+    pub state: String,
+    pub dataset: Option<ZfsDataset>,
+}
+
+impl ZfsPool {
+    pub fn for_dataset(dataset: &str) -> Option<Self> {
+        let name = dataset.split('/').next()?;
+        let path = Path::new("/proc/spl/kstat/zfs").join(name);
+
+        let state = fs::read_to_string(path.join("state")).ok()?;
+
+        Some(Self {
+            name: name.to_string(),
+            state: state.trim().to_string(),
+            dataset: find_dataset(&path, dataset),
+        })
+    }
+}
+
+fn find_dataset(pool_path: &Path, dataset: &str) -> Option<ZfsDataset> {
+    fs::read_dir(pool_path)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with("objset-"))
+        .filter_map(|entry| fs::read_to_string(entry.path()).ok())
+        .map(|content| parse_kstat(&content))
+        .find(|values| values.get("dataset_name").map(String::as_str) == Some(dataset))
+        .map(|values| {
+            let number = |key: &str| {
+                values
+                    .get(key)
+                    .and_then(|value| value.parse::<u64>().ok())
+                    .unwrap_or(0)
+            };
+
+            ZfsDataset {
+                name: dataset.to_string(),
+                reads: number("reads"),
+                writes: number("writes"),
+                read_bytes: number("nread"),
+                written_bytes: number("nwritten"),
+            }
+        })
+}
+
+fn parse_kstat(content: &str) -> HashMap<String, String> {
+    content
+        .lines()
+        .skip(2)
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let key = fields.next()?;
+            let value = fields.nth(1)?;
+
+            Some((key.to_string(), value.to_string()))
+        })
+        .collect()
+}
@@ -0,0 +1,434 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use serde::Serialize;
+
+static PNP_IDS_PATHS: [&str; 2] = ["/usr/share/hwdata/pnp.ids", "/usr/share/misc/pnp.ids"];
+
+static EMBEDDED_MANUFACTURERS: &[(&str, &str)] = &[
+    ("ACI", "Ancor Communications Inc"),
+    ("ACR", "Acer Technologies"),
+    ("AOC", "AOC International"),
+    ("APP", "Apple Computer Inc"),
+    ("AUO", "AU Optronics"),
+    ("AUS", "ASUSTek COMPUTER INC"),
+    ("BNQ", "BenQ Corporation"),
+    ("BOE", "BOE Technology"),
+    ("CMN", "Chimei Innolux Corporation"),
+    ("DEL", "Dell Inc."),
+    ("ENC", "EIZO Corporation"),
+    ("GBT", "Gigabyte Technology"),
+    ("GSM", "LG Electronics"),
+    ("HWP", "HP Inc."),
+    ("IVM", "Iiyama North America"),
+    ("LEN", "Lenovo Group Limited"),
+    ("LGD", "LG Display"),
+    ("MSI", "Micro-Star International"),
+    ("NEC", "NEC Corporation"),
+    ("PHL", "Philips Consumer Electronics Company"),
+    ("RHT", "Red Hat, Inc."),
+    ("SAM", "Samsung Electric Company"),
+    ("SDC", "Samsung Display Corp."),
+    ("SHP", "Sharp Corporation"),
+    ("SNY", "Sony"),
+    ("VSC", "ViewSonic Corporation"),
+];
+
+#[derive(Debug, Serialize)]
+pub struct Resolution {
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Edid {
+    pub manufacturer_id: String,
+    pub manufacturer: Option<String>,
+    pub product_code: u16,
+    pub model: Option<String>,
+    pub serial: Option<String>,
+    pub year: Option<u16>,
+    pub width_mm: Option<u32>,
+    pub height_mm: Option<u32>,
+    pub native_resolution: Option<Resolution>,
+    pub refresh_rate: Option<f64>,
+    pub interlaced: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DisplayInfo {
+    pub card: String,
+    pub connector: String,
+    pub enabled: bool,
+    pub modes: Vec<String>,
+    pub edid: Option<Edid>,
+}
+
+impl DisplayInfo {
+    pub fn get_displays() -> Result<Vec<DisplayInfo>> {
+        let path: &str = "/sys/class/drm/";
+
+        let entries = fs::read_dir(path)
+            .context("display: DisplayInfo::get_displays() - Error reading directory")?;
+
+        let mut displays: Vec<DisplayInfo> = Vec::new();
+
+        for entry in entries {
+            let entry = entry.context("display: DisplayInfo::get_displays() - DirEntry error")?;
+
+            let Ok(name) = entry.file_name().into_string() else {
+                continue;
+            };
+
+            let Some((card, connector)) = name.split_once('-') else {
+                continue;
+            };
+
+            if !card.starts_with("card") {
+                continue;
+            }
+
+            let connector_path = entry.path();
+
+            let status = fs::read_to_string(connector_path.join("status")).unwrap_or_default();
+
+            if status.trim() != "connected" {
+                continue;
+            }
+
+            displays.push(DisplayInfo::from_connector(
+                &connector_path,
+                card,
+                connector,
+            ));
+        }
+
+        if displays.is_empty() {
+            bail!("display: DisplayInfo::get_displays() - No connected displays found");
+        }
+
+        displays.sort_by(|a, b| {
+            b.enabled
+                .cmp(&a.enabled)
+                .then_with(|| a.card.cmp(&b.card))
+                .then_with(|| a.connector.cmp(&b.connector))
+        });
+
+        Ok(displays)
+    }
+
+    fn from_connector(connector_path: &Path, card: &str, connector: &str) -> Self {
+        let enabled = fs::read_to_string(connector_path.join("enabled"))
+            .is_ok_and(|value| value.trim() == "enabled");
+
+        let modes = fs::read_to_string(connector_path.join("modes"))
+            .unwrap_or_default()
+            .lines()
+            .map(|mode| mode.trim().to_string())
+            .filter(|mode| !mode.is_empty())
+            .collect();
+
+        let edid = fs::read(connector_path.join("edid"))
+            .ok()
+            .and_then(|data| Edid::parse(&data).ok())
+            .map(|mut edid| {
+                edid.manufacturer = lookup_manufacturer(&edid.manufacturer_id);
+                edid
+            });
+
+        Self {
+            card: card.to_string(),
+            connector: connector.to_string(),
+            enabled,
+            modes,
+            edid,
+        }
+    }
+
+    pub fn resolution(&self) -> Option<(u32, u32)> {
+        if let Some(resolution) = self
+            .edid
+            .as_ref()
+            .and_then(|edid| edid.native_resolution.as_ref())
+        {
+            return Some((resolution.width, resolution.height));
+        }
+
+        let (width, height) = self.modes.first()?.split_once('x')?;
+        let height = height.trim_end_matches(|c: char| !c.is_ascii_digit());
+
+        Some((width.parse().ok()?, height.parse().ok()?))
+    }
+}
+
+impl Edid {
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        if data.len() < 128 {
+            bail!(
+                "display: Edid::parse() - EDID is {} bytes, expected at least 128",
+                data.len()
+            );
+        }
+
+        if data[..8] != [0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00] {
+            bail!("display: Edid::parse() - Missing EDID header");
+        }
+
+        if data[..128]
+            .iter()
+            .fold(0u8, |sum, byte| sum.wrapping_add(*byte))
+            != 0
+        {
+            bail!("display: Edid::parse() - Base block checksum mismatch");
+        }
+
+        let manufacturer_id = decode_manufacturer(u16::from_be_bytes([data[8], data[9]]));
+        let product_code = u16::from_le_bytes([data[10], data[11]]);
+        let serial_number = u32::from_le_bytes([data[12], data[13], data[14], data[15]]);
+
+        let year = match data[17] {
+            0 | 0xff => None,
+            year => Some(1990 + year as u16),
+        };
+
+        let mut edid = Self {
+            manufacturer: None,
+            manufacturer_id,
+            product_code,
+            model: None,
+            serial: (serial_number != 0).then(|| serial_number.to_string()),
+            year,
+            width_mm: (data[21] != 0).then(|| data[21] as u32 * 10),
+            height_mm: (data[22] != 0).then(|| data[22] as u32 * 10),
+            native_resolution: None,
+            refresh_rate: None,
+            interlaced: false,
+        };
+
+        for descriptor in data[54..126].chunks_exact(18) {
+            if descriptor[0] != 0 || descriptor[1] != 0 {
+                if edid.native_resolution.is_none() {
+                    edid.parse_timing(descriptor);
+                }
+                continue;
+            }
+
+            match descriptor[3] {
+                0xfc => edid.model = descriptor_text(descriptor),
+                0xff => edid.serial = descriptor_text(descriptor).or(edid.serial),
+                _ => {}
+            }
+        }
+
+        Ok(edid)
+    }
+
+    fn parse_timing(&mut self, descriptor: &[u8]) {
+        let pixel_clock = u16::from_le_bytes([descriptor[0], descriptor[1]]) as f64 * 10_000.0;
+
+        let h_active = descriptor[2] as u32 | ((descriptor[4] as u32 & 0xf0) << 4);
+        let h_blank = descriptor[3] as u32 | ((descriptor[4] as u32 & 0x0f) << 8);
+        let v_active = descriptor[5] as u32 | ((descriptor[7] as u32 & 0xf0) << 4);
+        let v_blank = descriptor[6] as u32 | ((descriptor[7] as u32 & 0x0f) << 8);
+
+        let width_mm = descriptor[12] as u32 | ((descriptor[14] as u32 & 0xf0) << 4);
+        let height_mm = descriptor[13] as u32 | ((descriptor[14] as u32 & 0x0f) << 8);
+
+        self.interlaced = descriptor[17] & 0x80 != 0;
+
+        let (width, height) = match self.interlaced {
+            true => (h_active, v_active * 2),
+            false => (h_active, v_active),
+        };
+
+        self.native_resolution = Some(Resolution { width, height });
+
+        let total = (h_active + h_blank) * (v_active + v_blank);
+
+        if total != 0 {
+            let refresh_rate = pixel_clock / total as f64;
+            self.refresh_rate = Some((refresh_rate * 100.0).round() / 100.0);
+        }
+
+        if width_mm != 0 && height_mm != 0 {
+            self.width_mm = Some(width_mm);
+            self.height_mm = Some(height_mm);
+        }
+    }
+
+    pub fn diagonal_inches(&self) -> Option<f64> {
+        let width = self.width_mm? as f64;
+        let height = self.height_mm? as f64;
+
+        Some(((width * width + height * height).sqrt() / 25.4 * 10.0).round() / 10.0)
+    }
+}
+
+fn decode_manufacturer(value: u16) -> String {
+    [10, 5, 0]
+        .iter()
+        .map(|shift| match (value >> shift) & 0x1f {
+            letter @ 1..=26 => (b'A' + letter as u8 - 1) as char,
+            _ => '?',
+        })
+        .collect()
+}
+
+fn lookup_manufacturer(id: &str) -> Option<String> {
+    let from_database = PNP_IDS_PATHS
+        .iter()
+        .find_map(|path| fs::read_to_string(path).ok())
+        .and_then(|content| {
+            content.lines().find_map(|line| {
+                let (pnp_id, name) = line.split_once('\t')?;
+                (pnp_id == id).then(|| name.trim().to_string())
+            })
+        });
+
+    from_database.or_else(|| {
+        EMBEDDED_MANUFACTURERS
+            .iter()
+            .find(|(pnp_id, _)| *pnp_id == id)
+            .map(|(_, name)| name.to_string())
+    })
+}
+
+fn descriptor_text(descriptor: &[u8]) -> Option<String> {
+    let text = &descriptor[5..18];
+    let end = text
+        .iter()
+        .position(|byte| *byte == 0x0a)
+        .unwrap_or(text.len());
+
+    let text: String = text[..end]
+        .iter()
+        .map(|byte| match byte {
+            0x20..=0x7e => *byte as char,
+            _ => ' ',
+        })
+        .collect();
+
+    let text = text.trim();
+
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    static DELL_U2415: &[u8] = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/edid/dell-u2415.bin"
+    ));
+    static SAMSUNG_1080I: &[u8] = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/edid/samsung-1080i.bin"
+    ));
+
+    #[test]
+    fn detailed_timing_and_descriptors() {
+        let edid = Edid::parse(DELL_U2415).unwrap();
+
+        assert_eq!(edid.manufacturer_id, "DEL");
+        assert_eq!(edid.manufacturer, None);
+        assert_eq!(edid.product_code, 0xa0a4);
+        assert_eq!(edid.model.as_deref(), Some("DELL U2415"));
+        assert_eq!(edid.serial.as_deref(), Some("7MT0167B0L2L"));
+        assert_eq!(edid.year, Some(2016));
+
+        let resolution = edid.native_resolution.as_ref().unwrap();
+        assert_eq!((resolution.width, resolution.height), (1920, 1200));
+        assert_eq!(edid.refresh_rate, Some(59.95));
+        assert!(!edid.interlaced);
+
+        assert_eq!((edid.width_mm, edid.height_mm), (Some(518), Some(324)));
+        assert_eq!(edid.diagonal_inches(), Some(24.1));
+    }
+
+    #[test]
+    fn interlaced_timing_without_serial() {
+        let edid = Edid::parse(SAMSUNG_1080I).unwrap();
+
+        assert_eq!(edid.manufacturer_id, "SAM");
+        assert_eq!(edid.model.as_deref(), Some("SAMSUNG"));
+        assert_eq!(edid.serial, None);
+        assert!(edid.interlaced);
+
+        let resolution = edid.native_resolution.as_ref().unwrap();
+        assert_eq!((resolution.width, resolution.height), (1920, 1080));
+        assert_eq!(edid.refresh_rate, Some(60.05));
+        assert_eq!((edid.width_mm, edid.height_mm), (Some(1600), Some(900)));
+    }
+
+    #[test]
+    fn numeric_serial_without_descriptor() {
+        let mut data = DELL_U2415.to_vec();
+        data[72..90].copy_from_slice(&[0, 0, 0, 0x10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        data[127] = 0;
+        data[127] = data[..128]
+            .iter()
+            .fold(0u8, |sum, byte| sum.wrapping_sub(*byte));
+
+        let edid = Edid::parse(&data).unwrap();
+
+        assert_eq!(edid.serial.as_deref(), Some("1280062000"));
+    }
+
+    #[test]
+    fn rejects_bad_header() {
+        let mut data = DELL_U2415.to_vec();
+        data[0] = 0xff;
+
+        assert!(Edid::parse(&data).is_err());
+    }
+
+    #[test]
+    fn rejects_bad_checksum() {
+        let mut data = DELL_U2415.to_vec();
+        data[127] = data[127].wrapping_add(1);
+
+        assert!(Edid::parse(&data).is_err());
+    }
+
+    #[test]
+    fn rejects_truncated_block() {
+        assert!(Edid::parse(&DELL_U2415[..100]).is_err());
+        assert!(Edid::parse(&[]).is_err());
+    }
+
+    #[test]
+    fn manufacturer_fallback_table() {
+        assert_eq!(
+            EMBEDDED_MANUFACTURERS
+                .iter()
+                .find(|(id, _)| *id == "DEL")
+                .map(|(_, name)| *name),
+            Some("Dell Inc.")
+        );
+        assert_eq!(decode_manufacturer(0x10ac), "DEL");
+        assert_eq!(decode_manufacturer(0), "???");
+    }
+
+    #[test]
+    fn bad_edid_falls_back_to_modes() {
+        let path = env::temp_dir().join(format!("atlasfetch-edid-{}", std::process::id()));
+        fs::create_dir_all(&path).unwrap();
+
+        let mut data = DELL_U2415.to_vec();
+        data[127] = data[127].wrapping_add(1);
+        fs::write(path.join("edid"), &data).unwrap();
+        fs::write(path.join("modes"), "2560x1440\n1920x1080\n").unwrap();
+        fs::write(path.join("enabled"), "enabled\n").unwrap();
+
+        let display = DisplayInfo::from_connector(&path, "card0", "DP-1");
+        fs::remove_dir_all(&path).unwrap();
+
+        assert!(display.edid.is_none());
+        assert!(display.enabled);
+        assert_eq!(display.resolution(), Some((2560, 1440)));
+    }
+}
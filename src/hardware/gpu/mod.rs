@@ -1,61 +1,331 @@
-use std::process::Command;
-use std::str;
+use std::fs;
+use std::path::Path;
 
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
+use serde::Serialize;
 
-#[derive(Debug)]
+use crate::hardware::pci_ids::{self, parse_hex};
+
+static VIRTUAL_VENDORS: [u16; 6] = [0x1234, 0x1414, 0x15ad, 0x1af4, 0x1b36, 0x80ee];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum GpuClass {
+    Vga,
+    Controller3D,
+    Display,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum GpuKind {
+    Integrated,
+    Discrete,
+    Virtual,
+    Unknow,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PcieLink {
+    pub speed: String,
+    pub width: u8,
+    pub max_speed: Option<String>,
+    pub max_width: Option<u8>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct GpuClocks {
+    pub current_mhz: Option<u32>,
+    pub max_mhz: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
 pub struct GpuInfo {
     pub vendor_name: String,
     pub device_name: String,
+    pub subsystem_name: Option<String>,
+    pub vendor_id: u16,
+    pub device_id: u16,
+    pub subsystem_vendor_id: u16,
+    pub subsystem_device_id: u16,
+    pub pci_address: String,
+    pub class: GpuClass,
+    pub kind: GpuKind,
+    pub drm_card: Option<String>,
+    pub driver: Option<String>,
+    pub boot_vga: bool,
+    pub vram_total: Option<u64>,
+    pub vram_used: Option<u64>,
+    pub clocks: GpuClocks,
+    pub pcie_link: Option<PcieLink>,
 }
 
 impl GpuInfo {
-    pub fn new() -> Result<Self> {
-        let (vendor_name, device_name) = get_gpu_info()?;
+    pub fn get_gpus() -> Result<Vec<GpuInfo>> {
+        let path: &str = "/sys/bus/pci/devices/";
+
+        let devices =
+            fs::read_dir(path).context("gpu: GpuInfo::get_gpus() - Error reading directory")?;
+
+        let mut gpus: Vec<GpuInfo> = Vec::new();
+
+        for entry in devices {
+            let device_path = entry
+                .context("gpu: GpuInfo::get_gpus() - DirEntry error")?
+                .path();
+
+            let class = match read_attribute(&device_path, "class") {
+                Ok(class) => u32::from_str_radix(class.trim_start_matches("0x"), 16).unwrap_or(0),
+                Err(_) => continue,
+            };
+
+            let class = match class >> 8 {
+                0x0300 => GpuClass::Vga,
+                0x0302 => GpuClass::Controller3D,
+                0x0380 => GpuClass::Display,
+                _ => continue,
+            };
+
+            let Ok(gpu) = GpuInfo::from_device(&device_path, class) else {
+                continue;
+            };
+
+            gpus.push(gpu);
+        }
+
+        if gpus.is_empty() {
+            bail!("gpu: GpuInfo::get_gpus() - No display controllers found on the PCI bus");
+        }
+
+        gpus.sort_by(|a, b| {
+            b.boot_vga
+                .cmp(&a.boot_vga)
+                .then_with(|| a.pci_address.cmp(&b.pci_address))
+        });
+
+        Ok(gpus)
+    }
+
+    fn from_device(device_path: &Path, class: GpuClass) -> Result<Self> {
+        let vendor_id = parse_hex(&read_attribute(device_path, "vendor")?);
+        let device_id = parse_hex(&read_attribute(device_path, "device")?);
+        let subsystem_vendor_id =
+            parse_hex(&read_attribute(device_path, "subsystem_vendor").unwrap_or_default());
+        let subsystem_device_id =
+            parse_hex(&read_attribute(device_path, "subsystem_device").unwrap_or_default());
+
+        let names = pci_ids::lookup(
+            vendor_id,
+            device_id,
+            Some((subsystem_vendor_id, subsystem_device_id)),
+        );
+
+        let pci_address = file_name(&fs::canonicalize(device_path).with_context(|| {
+            format!(
+                "gpu: GpuInfo::from_device() - Error resolving {}",
+                device_path.display()
+            )
+        })?)
+        .unwrap_or_default();
+
+        let driver = fs::read_link(device_path.join("driver"))
+            .ok()
+            .and_then(|link| file_name(&link));
+
+        let boot_vga = read_attribute(device_path, "boot_vga").is_ok_and(|value| value == "1");
+
+        let drm_card = fs::read_dir(device_path.join("drm"))
+            .ok()
+            .and_then(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok())
+                    .filter_map(|entry| entry.file_name().into_string().ok())
+                    .find(|name| name.starts_with("card"))
+            });
+
+        let kind = get_kind(device_path, &pci_address, vendor_id);
+
+        let vram_total = read_number(device_path, "mem_info_vram_total");
+        let vram_used = read_number(device_path, "mem_info_vram_used");
+        let clocks = get_clocks(device_path, drm_card.as_deref());
+        let pcie_link = get_pcie_link(device_path);
 
         Ok(Self {
-            vendor_name,
-            device_name,
+            vendor_name: names
+                .vendor
+                .unwrap_or_else(|| format!("Vendor {:04x}", vendor_id)),
+            device_name: names
+                .device
+                .unwrap_or_else(|| format!("Device {:04x}", device_id)),
+            subsystem_name: names.subsystem,
+            vendor_id,
+            device_id,
+            subsystem_vendor_id,
+            subsystem_device_id,
+            pci_address,
+            class,
+            kind,
+            drm_card,
+            driver,
+            boot_vga,
+            vram_total,
+            vram_used,
+            clocks,
+            pcie_link,
         })
     }
 }
 
-fn get_gpu_info() -> Result<(String, String)> {
-    let output = Command::new("lspci")
-        .arg("-vmm")
-        .arg("-d")
-        .arg("::0300")
-        .output()
-        .context("gpu: get_gpu_info - lspci runtime error")?;
+fn get_kind(device_path: &Path, pci_address: &str, vendor_id: u16) -> GpuKind {
+    if VIRTUAL_VENDORS.contains(&vendor_id) {
+        return GpuKind::Virtual;
+    }
 
-    let output_str = str::from_utf8(&output.stdout)
-        .context("gpu: get_gpu_info - output_str: UTF-8 conversion error")?;
+    if read_attribute(device_path, "removable").is_ok_and(|value| value == "removable")
+        || in_physical_slot(pci_address)
+    {
+        return GpuKind::Discrete;
+    }
 
-    let mut vendor = None;
-    let mut device = None;
+    let Some((bus, slot)) = pci_address.rsplit_once(':') else {
+        return GpuKind::Unknow;
+    };
+    let on_root_bus = bus
+        .rsplit(':')
+        .next()
+        .is_some_and(|bus| parse_hex(bus) == 0);
 
-    for line in output_str.lines() {
-        let parts: Vec<&str> = line.splitn(2, ':').collect();
-        if parts.len() != 2 {
-            continue;
-        }
+    match vendor_id {
+        0x8086 if on_root_bus && slot == "02.0" => GpuKind::Integrated,
+        0x8086 if !on_root_bus => GpuKind::Discrete,
+        0x1002 if bus_has_vendor(device_path, bus, 0x1022) => GpuKind::Integrated,
+        0x1002 | 0x10de => GpuKind::Discrete,
+        _ => GpuKind::Unknow,
+    }
+}
+
+fn in_physical_slot(pci_address: &str) -> bool {
+    let Some((device, _)) = pci_address.rsplit_once('.') else {
+        return false;
+    };
+
+    fs::read_dir("/sys/bus/pci/slots")
+        .map(|slots| {
+            slots
+                .filter_map(|slot| slot.ok())
+                .any(|slot| read_attribute(&slot.path(), "address").is_ok_and(|a| a == device))
+        })
+        .unwrap_or(false)
+}
 
-        let key = parts[0].trim();
-        let value = parts[1].trim();
+fn bus_has_vendor(device_path: &Path, bus: &str, vendor_id: u16) -> bool {
+    let Some(parent) = fs::canonicalize(device_path)
+        .ok()
+        .and_then(|path| path.parent().map(Path::to_path_buf))
+    else {
+        return false;
+    };
 
-        match key {
-            "Vendor" => vendor = Some(value.to_string()),
-            "Device" => device = Some(value.to_string()),
-            _ => {}
+    let Ok(entries) = fs::read_dir(&parent) else {
+        return false;
+    };
+
+    entries.filter_map(|entry| entry.ok()).any(|entry| {
+        entry
+            .file_name()
+            .to_str()
+            .is_some_and(|name| name.starts_with(&format!("{}:", bus)))
+            && read_attribute(&entry.path(), "vendor")
+                .is_ok_and(|vendor| parse_hex(&vendor) == vendor_id)
+    })
+}
+
+fn get_clocks(device_path: &Path, drm_card: Option<&str>) -> GpuClocks {
+    if let Ok(sclk) = read_attribute(device_path, "pp_dpm_sclk") {
+        let mut clocks = GpuClocks::default();
+
+        for line in sclk.lines() {
+            let Some(mhz) = line.split_whitespace().nth(1).and_then(|value| {
+                value
+                    .to_lowercase()
+                    .strip_suffix("mhz")?
+                    .parse::<u32>()
+                    .ok()
+            }) else {
+                continue;
+            };
+
+            if line.trim_end().ends_with('*') {
+                clocks.current_mhz = Some(mhz);
+            }
+
+            clocks.max_mhz = clocks.max_mhz.max(Some(mhz));
         }
 
-        if vendor.is_some() && device.is_some() {
-            break;
+        return clocks;
+    }
+
+    if let Some(card) = drm_card {
+        let card_path = device_path.join("drm").join(card);
+
+        if card_path.join("gt_max_freq_mhz").exists() {
+            return GpuClocks {
+                current_mhz: read_number(&card_path, "gt_act_freq_mhz")
+                    .or_else(|| read_number(&card_path, "gt_cur_freq_mhz"))
+                    .map(|mhz| mhz as u32),
+                max_mhz: read_number(&card_path, "gt_max_freq_mhz").map(|mhz| mhz as u32),
+            };
         }
     }
 
-    Ok((
-        vendor.context("gpu: error getting vendor information")?,
-        device.context("gpu: error getting device information")?,
-    ))
+    let xe_freq = device_path.join("tile0/gt0/freq0");
+
+    GpuClocks {
+        current_mhz: read_number(&xe_freq, "act_freq")
+            .or_else(|| read_number(&xe_freq, "cur_freq"))
+            .map(|mhz| mhz as u32),
+        max_mhz: read_number(&xe_freq, "max_freq").map(|mhz| mhz as u32),
+    }
+}
+
+fn get_pcie_link(device_path: &Path) -> Option<PcieLink> {
+    let speed = read_attribute(device_path, "current_link_speed").ok()?;
+    let width = read_attribute(device_path, "current_link_width")
+        .ok()?
+        .parse::<u8>()
+        .ok()?;
+
+    if speed.starts_with("Unknown") || width == 0 {
+        return None;
+    }
+
+    Some(PcieLink {
+        speed,
+        width,
+        max_speed: read_attribute(device_path, "max_link_speed").ok(),
+        max_width: read_number(device_path, "max_link_width").map(|width| width as u8),
+    })
+}
+
+fn read_number(device_path: &Path, attribute: &str) -> Option<u64> {
+    read_attribute(device_path, attribute)
+        .ok()?
+        .parse::<u64>()
+        .ok()
+}
+
+fn read_attribute(device_path: &Path, attribute: &str) -> Result<String> {
+    let content = fs::read_to_string(device_path.join(attribute)).with_context(|| {
+        format!(
+            "gpu: read_attribute - error reading {}/{}",
+            device_path.display(),
+            attribute
+        )
+    })?;
+
+    Ok(content.trim().to_string())
+}
+
+fn file_name(path: &Path) -> Option<String> {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .map(String::from)
 }
@@ -0,0 +1,369 @@
+use anyhow::{Result, bail};
+use serde::Serialize;
+
+use crate::hardware::smbios::{self, Structure};
+
+#[derive(Debug, Serialize)]
+pub struct MemoryDevice {
+    pub locator: Option<String>,
+    pub bank_locator: Option<String>,
+    pub size_kib: Option<u64>,
+    pub memory_type: String,
+    pub form_factor: String,
+    pub speed_mts: Option<u32>,
+    pub configured_speed_mts: Option<u32>,
+    pub manufacturer: Option<String>,
+    pub part_number: Option<String>,
+    pub serial_number: Option<String>,
+    pub rank: Option<u8>,
+    pub configured_voltage_mv: Option<u16>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DimmInfo {
+    pub max_capacity_kib: Option<u64>,
+    pub slot_count: usize,
+    pub devices: Vec<MemoryDevice>,
+}
+
+impl DimmInfo {
+    pub fn new() -> Result<Self> {
+        let table = smbios::read_table()?;
+
+        Self::from_table(&table)
+    }
+
+    pub fn from_table(table: &[u8]) -> Result<Self> {
+        let structures = smbios::structures(table);
+
+        let arrays: Vec<&Structure> = structures
+            .iter()
+            .filter(|structure| structure.kind == 16 && structure.byte(0x05) == Some(0x03))
+            .collect();
+
+        let devices: Vec<MemoryDevice> = structures
+            .iter()
+            .filter(|structure| structure.kind == 17)
+            .filter(|structure| {
+                arrays.is_empty()
+                    || arrays
+                        .iter()
+                        .any(|array| structure.word(0x04) == Some(array.handle))
+            })
+            .map(MemoryDevice::from_structure)
+            .collect();
+
+        if devices.is_empty() {
+            bail!("memory: DimmInfo::from_table() - No memory devices in the SMBIOS table");
+        }
+
+        let max_capacity_kib = arrays
+            .iter()
+            .map(|array| match array.dword(0x07) {
+                Some(0x8000_0000) => array.qword(0x0f).map(|bytes| bytes / 1024),
+                capacity => capacity.map(|kib| kib as u64),
+            })
+            .sum::<Option<u64>>()
+            .filter(|kib| *kib > 0);
+
+        Ok(Self {
+            max_capacity_kib,
+            slot_count: devices.len(),
+            devices,
+        })
+    }
+
+    pub fn populated(&self) -> usize {
+        self.devices
+            .iter()
+            .filter(|device| device.size_kib.is_some())
+            .count()
+    }
+}
+
+impl MemoryDevice {
+    fn from_structure(structure: &Structure) -> Self {
+        let size_kib = match structure.word(0x0c) {
+            None | Some(0) | Some(0xffff) => None,
+            Some(0x7fff) => structure
+                .dword(0x1c)
+                .map(|mib| (mib & 0x7fff_ffff) as u64 * 1024),
+            Some(size) if size & 0x8000 != 0 => Some((size & 0x7fff) as u64),
+            Some(size) => Some(size as u64 * 1024),
+        }
+        .filter(|kib| *kib > 0);
+
+        let speed_mts = match structure.word(0x15) {
+            Some(0xffff) => structure.dword(0x54),
+            speed => speed.map(|speed| speed as u32),
+        }
+        .filter(|speed| *speed != 0);
+
+        let configured_speed_mts = match structure.word(0x20) {
+            Some(0xffff) => structure.dword(0x58),
+            speed => speed.map(|speed| speed as u32),
+        }
+        .filter(|speed| *speed != 0);
+
+        Self {
+            locator: structure.string(0x10),
+            bank_locator: structure.string(0x11),
+            size_kib,
+            memory_type: memory_type(structure.byte(0x12).unwrap_or(0x02)).to_string(),
+            form_factor: form_factor(structure.byte(0x0e).unwrap_or(0x02)).to_string(),
+            speed_mts,
+            configured_speed_mts,
+            manufacturer: structure.string(0x17),
+            serial_number: structure.string(0x18),
+            part_number: structure.string(0x1a),
+            rank: structure
+                .byte(0x1b)
+                .map(|attributes| attributes & 0x0f)
+                .filter(|rank| *rank != 0),
+            configured_voltage_mv: structure.word(0x26).filter(|mv| *mv != 0),
+        }
+    }
+}
+
+fn memory_type(value: u8) -> &'static str {
+    match value {
+        0x01 => "Other",
+        0x03 => "DRAM",
+        0x04 => "EDRAM",
+        0x05 => "VRAM",
+        0x06 => "SRAM",
+        0x07 => "RAM",
+        0x08 => "ROM",
+        0x09 => "Flash",
+        0x0a => "EEPROM",
+        0x0b => "FEPROM",
+        0x0c => "EPROM",
+        0x0d => "CDRAM",
+        0x0e => "3DRAM",
+        0x0f => "SDRAM",
+        0x10 => "SGRAM",
+        0x11 => "RDRAM",
+        0x12 => "DDR",
+        0x13 => "DDR2",
+        0x14 => "DDR2 FB-DIMM",
+        0x18 => "DDR3",
+        0x19 => "FBD2",
+        0x1a => "DDR4",
+        0x1b => "LPDDR",
+        0x1c => "LPDDR2",
+        0x1d => "LPDDR3",
+        0x1e => "LPDDR4",
+        0x1f => "Logical non-volatile device",
+        0x20 => "HBM",
+        0x21 => "HBM2",
+        0x22 => "DDR5",
+        0x23 => "LPDDR5",
+        0x24 => "HBM3",
+        _ => "Unknow",
+    }
+}
+
+fn form_factor(value: u8) -> &'static str {
+    match value {
+        0x01 => "Other",
+        0x03 => "SIMM",
+        0x04 => "SIP",
+        0x05 => "Chip",
+        0x06 => "DIP",
+        0x07 => "ZIP",
+        0x08 => "Proprietary Card",
+        0x09 => "DIMM",
+        0x0a => "TSOP",
+        0x0b => "Row of chips",
+        0x0c => "RIMM",
+        0x0d => "SODIMM",
+        0x0e => "SRIMM",
+        0x0f => "FB-DIMM",
+        0x10 => "Die",
+        0x11 => "CAMM",
+        _ => "Unknow",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardware::smbios::encode_structure;
+
+    fn array(handle: u16, capacity_kib: u32, extended_bytes: u64) -> Vec<u8> {
+        encode_structure(
+            16,
+            handle,
+            0x17,
+            &[
+                (0x05, &[0x03]),
+                (0x07, &capacity_kib.to_le_bytes()),
+                (0x0d, &4u16.to_le_bytes()),
+                (0x0f, &extended_bytes.to_le_bytes()),
+            ],
+            &[],
+        )
+    }
+
+    fn device(
+        handle: u16,
+        array: u16,
+        size: u16,
+        extended_mib: u32,
+        speeds: [u16; 2],
+        extended_speeds: [u32; 2],
+        strings: &[&str],
+    ) -> Vec<u8> {
+        encode_structure(
+            17,
+            handle,
+            0x5c,
+            &[
+                (0x04, &array.to_le_bytes()),
+                (0x0c, &size.to_le_bytes()),
+                (0x0e, &[0x09]),
+                (0x10, &[1, 2]),
+                (0x12, &[0x22]),
+                (0x15, &speeds[0].to_le_bytes()),
+                (0x17, &[3, 4]),
+                (0x1a, &[5, 0x02]),
+                (0x1c, &extended_mib.to_le_bytes()),
+                (0x20, &speeds[1].to_le_bytes()),
+                (0x26, &1100u16.to_le_bytes()),
+                (0x54, &extended_speeds[0].to_le_bytes()),
+                (0x58, &extended_speeds[1].to_le_bytes()),
+            ],
+            strings,
+        )
+    }
+
+    #[test]
+    fn extended_capacity_size_and_speed() {
+        let mut table = array(0x1000, 0x8000_0000, 4 * 1024 * 1024 * 1024 * 1024);
+        table.extend(device(
+            0x1100,
+            0x1000,
+            0x7fff,
+            65536,
+            [0xffff, 0xffff],
+            [8800, 8000],
+            &[
+                "DIMM_A1",
+                "BANK 0",
+                "Samsung",
+                "1234ABCD",
+                "M321R8GA0PB0-CWM",
+            ],
+        ));
+        table.extend(device(
+            0x1101,
+            0x1000,
+            0x8000 | 512,
+            0,
+            [4800, 4400],
+            [0, 0],
+            &["DIMM_B1", "BANK 1", "Micron", "5678", "MTC20F2085S1RC48BA1"],
+        ));
+
+        let dimm_info = DimmInfo::from_table(&table).unwrap();
+
+        assert_eq!(dimm_info.max_capacity_kib, Some(4 * 1024 * 1024 * 1024));
+        assert_eq!(dimm_info.slot_count, 2);
+        assert_eq!(dimm_info.populated(), 2);
+
+        let first = &dimm_info.devices[0];
+        assert_eq!(first.locator.as_deref(), Some("DIMM_A1"));
+        assert_eq!(first.bank_locator.as_deref(), Some("BANK 0"));
+        assert_eq!(first.size_kib, Some(64 * 1024 * 1024));
+        assert_eq!(first.memory_type, "DDR5");
+        assert_eq!(first.form_factor, "DIMM");
+        assert_eq!(first.speed_mts, Some(8800));
+        assert_eq!(first.configured_speed_mts, Some(8000));
+        assert_eq!(first.manufacturer.as_deref(), Some("Samsung"));
+        assert_eq!(first.serial_number.as_deref(), Some("1234ABCD"));
+        assert_eq!(first.part_number.as_deref(), Some("M321R8GA0PB0-CWM"));
+        assert_eq!(first.rank, Some(2));
+        assert_eq!(first.configured_voltage_mv, Some(1100));
+
+        let second = &dimm_info.devices[1];
+        assert_eq!(second.size_kib, Some(512));
+        assert_eq!(second.speed_mts, Some(4800));
+        assert_eq!(second.configured_speed_mts, Some(4400));
+    }
+
+    #[test]
+    fn empty_slot_and_plain_capacity() {
+        let mut table = array(0x1000, 64 * 1024 * 1024, 0);
+        table.extend(device(
+            0x1100,
+            0x1000,
+            16384,
+            0,
+            [3200, 3200],
+            [0, 0],
+            &[
+                "ChannelA-DIMM0",
+                "BANK 0",
+                "Kingston",
+                "ABCD",
+                "KF3200C16D4/16GX",
+            ],
+        ));
+        table.extend(device(
+            0x1101,
+            0x1000,
+            0,
+            0,
+            [0, 0],
+            [0, 0],
+            &["ChannelB-DIMM0", "BANK 2", "NO DIMM", "NO DIMM", "NO DIMM"],
+        ));
+
+        let dimm_info = DimmInfo::from_table(&table).unwrap();
+
+        assert_eq!(dimm_info.max_capacity_kib, Some(64 * 1024 * 1024));
+        assert_eq!(dimm_info.slot_count, 2);
+        assert_eq!(dimm_info.populated(), 1);
+
+        assert_eq!(dimm_info.devices[0].size_kib, Some(16 * 1024 * 1024));
+
+        let empty = &dimm_info.devices[1];
+        assert_eq!(empty.locator.as_deref(), Some("ChannelB-DIMM0"));
+        assert_eq!(empty.size_kib, None);
+        assert_eq!(empty.speed_mts, None);
+        assert_eq!(empty.manufacturer, None);
+        assert_eq!(empty.serial_number, None);
+        assert_eq!(empty.part_number, None);
+    }
+
+    #[test]
+    fn ignores_devices_of_other_arrays() {
+        let mut table = array(0x1000, 8 * 1024 * 1024, 0);
+        table.extend(encode_structure(16, 0x2000, 0x17, &[(0x05, &[0x07])], &[]));
+        table.extend(device(
+            0x1100,
+            0x1000,
+            8192,
+            0,
+            [2400, 2400],
+            [0, 0],
+            &["A"],
+        ));
+        table.extend(device(0x2100, 0x2000, 64, 0, [0, 0], [0, 0], &["Cache"]));
+
+        let dimm_info = DimmInfo::from_table(&table).unwrap();
+
+        assert_eq!(dimm_info.slot_count, 1);
+        assert_eq!(dimm_info.devices[0].locator.as_deref(), Some("A"));
+    }
+
+    #[test]
+    fn truncated_table() {
+        let mut table = array(0x1000, 8 * 1024 * 1024, 0);
+        let device = device(0x1100, 0x1000, 8192, 0, [2400, 2400], [0, 0], &["A"]);
+        table.extend_from_slice(&device[..0x30]);
+
+        assert!(DimmInfo::from_table(&table).is_err());
+        assert!(DimmInfo::from_table(&[]).is_err());
+    }
+}
@@ -1,30 +1,101 @@
+pub mod dimm;
+
 use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::HashMap;
 use std::fs;
+use std::path::Path;
+
+use dimm::DimmInfo;
+
+#[derive(Debug, Serialize)]
+pub struct HugePagePool {
+    pub page_size_kib: u64,
+    pub total_pages: u64,
+    pub free_pages: u64,
+    pub reserved_pages: u64,
+    pub surplus_pages: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MemoryDetail {
+    pub buffers_kib: u64,
+    pub cached_kib: u64,
+    pub shmem_kib: u64,
+    pub slab_reclaimable_kib: u64,
+    pub slab_unreclaimable_kib: u64,
+    pub dirty_kib: u64,
+    pub writeback_kib: u64,
+    pub anon_huge_pages_kib: u64,
+    pub committed_kib: u64,
+    pub commit_limit_kib: u64,
+    pub huge_pages: Vec<HugePagePool>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SwapDevice {
+    pub name: String,
+    pub kind: String,
+    pub total_size: f64,
+    pub used_size: f64,
+    pub priority: i32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ZramDevice {
+    pub name: String,
+    pub algorithm: Option<String>,
+    pub disk_size: f64,
+    pub original_size: f64,
+    pub compressed_size: f64,
+    pub memory_used: f64,
+    pub compression_ratio: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ZswapInfo {
+    pub enabled: bool,
+    pub compressor: Option<String>,
+    pub zpool: Option<String>,
+    pub max_pool_percent: Option<u8>,
+    pub pool_size: Option<f64>,
+    pub stored_size: Option<f64>,
+}
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct SwapInfo {
     pub total_size: f64,
     pub free_size: f64,
     pub used_size: f64,
+    pub devices: Vec<SwapDevice>,
+    pub zram: Vec<ZramDevice>,
+    pub zswap: Option<ZswapInfo>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct MemoryInfo {
     pub total_size: f64,
     pub free_size: f64,
     pub used_size: f64,
     pub swap_info: SwapInfo,
+    pub detail: MemoryDetail,
+    pub dimms: Option<DimmInfo>,
 }
 
 impl MemoryInfo {
     pub fn new() -> Result<Self> {
-        let ram_info = Self::from_keys("MemTotal", "MemAvailable", None)?;
-        let swap_info = Self::from_keys("SwapTotal", "SwapFree", Some("SwapCached"))?;
+        let meminfo = read_meminfo().context("memory: MemoryInfo::new - error reading file")?;
+
+        let ram_info = Self::from_keys(&meminfo, "MemTotal", "MemAvailable");
+        let swap_info = Self::from_keys(&meminfo, "SwapTotal", "SwapFree");
 
         let swap_obj = SwapInfo {
             total_size: swap_info.0,
             free_size: swap_info.1,
             used_size: swap_info.2,
+            devices: get_swap_devices(),
+            zram: get_zram_devices(),
+            zswap: get_zswap(&meminfo),
         };
 
         Ok(Self {
@@ -32,46 +103,240 @@ impl MemoryInfo {
             free_size: ram_info.1,
             used_size: ram_info.2,
             swap_info: swap_obj,
+            detail: Self::detail(&meminfo),
+            dimms: DimmInfo::new().ok(),
         })
     }
 
+    fn detail(meminfo: &HashMap<String, u64>) -> MemoryDetail {
+        let [
+            buffers,
+            cached,
+            shmem,
+            slab_reclaimable,
+            slab_unreclaimable,
+            dirty,
+            writeback,
+            anon_huge_pages,
+            committed,
+            commit_limit,
+        ] = Self::from_kib_keys(
+            meminfo,
+            [
+                "Buffers",
+                "Cached",
+                "Shmem",
+                "SReclaimable",
+                "SUnreclaim",
+                "Dirty",
+                "Writeback",
+                "AnonHugePages",
+                "Committed_AS",
+                "CommitLimit",
+            ],
+        );
+
+        MemoryDetail {
+            buffers_kib: buffers,
+            cached_kib: cached,
+            shmem_kib: shmem,
+            slab_reclaimable_kib: slab_reclaimable,
+            slab_unreclaimable_kib: slab_unreclaimable,
+            dirty_kib: dirty,
+            writeback_kib: writeback,
+            anon_huge_pages_kib: anon_huge_pages,
+            committed_kib: committed,
+            commit_limit_kib: commit_limit,
+            huge_pages: get_huge_pages(),
+        }
+    }
+
     fn from_keys(
+        meminfo: &HashMap<String, u64>,
         total_key: &str,
         free_key: &str,
-        used_key: Option<&str>,
-    ) -> Result<(f64, f64, f64)> {
-        let meminfo = fs::read_to_string("/proc/meminfo")
-            .context("memory: from_keys - error reading file")?;
-        let mut info = (0.0, 0.0, 0.0);
-
-        for line in meminfo.lines() {
-            if line.starts_with(total_key) {
-                info.0 = parse_to_gb(line);
-            } else if line.starts_with(free_key) {
-                info.1 = parse_to_gb(line);
-            } else if let Some(key) = used_key {
-                if line.starts_with(key) {
-                    info.2 = parse_to_gb(line);
-                }
-            }
-        }
+    ) -> (f64, f64, f64) {
+        let [total, free] = Self::from_kib_keys(meminfo, [total_key, free_key]);
+        let (total, free) = (kb_to_gb(total as f64), kb_to_gb(free as f64));
+
+        (total, free, ((total - free) * 100.0).trunc() / 100.0)
+    }
+
+    fn from_kib_keys<const N: usize>(meminfo: &HashMap<String, u64>, keys: [&str; N]) -> [u64; N] {
+        keys.map(|key| meminfo.get(key).copied().unwrap_or(0))
+    }
+}
+
+fn read_meminfo() -> Result<HashMap<String, u64>> {
+    let meminfo = fs::read_to_string("/proc/meminfo")?;
+
+    Ok(meminfo
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            let kib = value.split_whitespace().next()?.parse().ok()?;
+
+            Some((key.to_string(), kib))
+        })
+        .collect())
+}
+
+fn get_huge_pages() -> Vec<HugePagePool> {
+    let Ok(entries) = fs::read_dir("/sys/kernel/mm/hugepages") else {
+        return Vec::new();
+    };
+
+    let mut pools: Vec<HugePagePool> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            let page_size_kib = name
+                .strip_prefix("hugepages-")?
+                .strip_suffix("kB")?
+                .parse()
+                .ok()?;
+
+            let path = entry.path();
+            let count = |file: &str| -> u64 {
+                fs::read_to_string(path.join(file))
+                    .ok()
+                    .and_then(|value| value.trim().parse().ok())
+                    .unwrap_or(0)
+            };
+
+            Some(HugePagePool {
+                page_size_kib,
+                total_pages: count("nr_hugepages"),
+                free_pages: count("free_hugepages"),
+                reserved_pages: count("resv_hugepages"),
+                surplus_pages: count("surplus_hugepages"),
+            })
+        })
+        .collect();
 
-        if used_key.is_none() {
-            info.2 = ((info.0 - info.1) * 100.0).trunc() / 100.0;
+    pools.sort_by_key(|pool| pool.page_size_kib);
+
+    pools
+}
+
+fn get_swap_devices() -> Vec<SwapDevice> {
+    let Ok(swaps) = fs::read_to_string("/proc/swaps") else {
+        return Vec::new();
+    };
+
+    let mut devices: Vec<SwapDevice> = Vec::new();
+
+    for line in swaps.lines().skip(1) {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+
+        if fields.len() < 5 {
+            continue;
         }
 
-        Ok(info)
+        devices.push(SwapDevice {
+            name: fields[0].replace("\\040", " "),
+            kind: fields[1].to_string(),
+            total_size: kb_to_gb(fields[2].parse().unwrap_or(0.0)),
+            used_size: kb_to_gb(fields[3].parse().unwrap_or(0.0)),
+            priority: fields[4].parse().unwrap_or(0),
+        });
     }
+
+    devices
 }
 
-fn parse_to_gb(line: &str) -> f64 {
-    let kb: f64 = line
-        .split_whitespace()
-        .nth(1)
-        .unwrap_or("0")
+fn get_zram_devices() -> Vec<ZramDevice> {
+    let Ok(entries) = fs::read_dir("/sys/block") else {
+        return Vec::new();
+    };
+
+    let mut devices: Vec<ZramDevice> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+
+            match name.starts_with("zram") {
+                true => get_zram_device(&entry.path(), name),
+                false => None,
+            }
+        })
+        .collect();
+
+    devices.sort_by(|a, b| a.name.cmp(&b.name));
+
+    devices
+}
+
+fn get_zram_device(path: &Path, name: String) -> Option<ZramDevice> {
+    let disk_size: f64 = fs::read_to_string(path.join("disksize"))
+        .ok()?
+        .trim()
         .parse()
-        .unwrap_or(0.0);
+        .ok()?;
+
+    if disk_size == 0.0 {
+        return None;
+    }
+
+    let mm_stat: Vec<f64> = fs::read_to_string(path.join("mm_stat"))
+        .unwrap_or_default()
+        .split_whitespace()
+        .map(|value| value.parse().unwrap_or(0.0))
+        .collect();
+
+    let stat = |index: usize| mm_stat.get(index).copied().unwrap_or(0.0);
+
+    let (original, compressed) = (stat(0), stat(1));
+
+    let algorithm = fs::read_to_string(path.join("comp_algorithm"))
+        .ok()
+        .and_then(|algorithms| selected_value(&algorithms));
+
+    Some(ZramDevice {
+        name,
+        algorithm,
+        disk_size: kb_to_gb(disk_size / 1024.0),
+        original_size: kb_to_gb(original / 1024.0),
+        compressed_size: kb_to_gb(compressed / 1024.0),
+        memory_used: kb_to_gb(stat(2) / 1024.0),
+        compression_ratio: (compressed > 0.0)
+            .then(|| (original / compressed * 100.0).trunc() / 100.0),
+    })
+}
+
+fn get_zswap(meminfo: &HashMap<String, u64>) -> Option<ZswapInfo> {
+    let parameters = Path::new("/sys/module/zswap/parameters");
+
+    let parameter = |name: &str| {
+        fs::read_to_string(parameters.join(name))
+            .ok()
+            .map(|value| value.trim().to_string())
+    };
+
+    let enabled = parameter("enabled")?;
+
+    let meminfo_value = |key: &str| meminfo.get(key).map(|kib| kb_to_gb(*kib as f64));
+
+    Some(ZswapInfo {
+        enabled: enabled == "Y" || enabled == "1",
+        compressor: parameter("compressor"),
+        zpool: parameter("zpool"),
+        max_pool_percent: parameter("max_pool_percent").and_then(|value| value.parse().ok()),
+        pool_size: meminfo_value("Zswap"),
+        stored_size: meminfo_value("Zswapped"),
+    })
+}
+
+fn selected_value(values: &str) -> Option<String> {
+    let values = values.trim();
+
+    match (values.find('['), values.find(']')) {
+        (Some(start), Some(end)) if start < end => Some(values[start + 1..end].to_string()),
+        _ => values.split_whitespace().next().map(String::from),
+    }
+}
 
+fn kb_to_gb(kb: f64) -> f64 {
     let gb = (kb / 1024.0 / 1024.0).trunc();
     let mb: f64 = ((kb / 1024.0 - (gb * 1024.0)) / 10.0).trunc() / 100.0;
     gb + mb
@@ -1,5 +1,9 @@
 pub mod battery;
+pub mod board;
 pub mod cpu;
 pub mod disk;
+pub mod display;
 pub mod gpu;
 pub mod memory;
+pub mod pci_ids;
+pub mod smbios;
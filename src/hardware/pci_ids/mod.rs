@@ -0,0 +1,124 @@
+use std::fs;
+
+static PCI_IDS_PATHS: [&str; 4] = [
+    "/usr/share/hwdata/pci.ids",
+    "/usr/share/misc/pci.ids",
+    "/usr/share/pci.ids",
+    "/usr/share/pciids/pci.ids",
+];
+
+static EMBEDDED_VENDORS: &[(u16, &str)] = &[
+    (0x1002, "Advanced Micro Devices, Inc. [AMD/ATI]"),
+    (0x1022, "Advanced Micro Devices, Inc. [AMD]"),
+    (0x102b, "Matrox Electronics Systems Ltd."),
+    (0x10de, "NVIDIA Corporation"),
+    (0x1234, "Technical Corp."),
+    (0x1414, "Microsoft Corporation"),
+    (0x15ad, "VMware"),
+    (0x1a03, "ASPEED Technology, Inc."),
+    (0x1af4, "Red Hat, Inc."),
+    (0x1b36, "Red Hat, Inc."),
+    (0x1ed5, "Moore Threads Technology Co.,Ltd"),
+    (0x5143, "Qualcomm Inc"),
+    (0x80ee, "InnoTek Systemberatung GmbH"),
+    (0x8086, "Intel Corporation"),
+];
+
+static EMBEDDED_DEVICES: &[(u16, u16, &str)] = &[
+    (0x1234, 0x1111, "QEMU Virtual Video Controller"),
+    (0x1414, 0x5353, "Hyper-V virtual VGA"),
+    (0x15ad, 0x0405, "SVGA II Adapter"),
+    (0x1af4, 0x1050, "Virtio 1.0 GPU"),
+    (0x1b36, 0x0100, "QXL paravirtual graphic card"),
+    (0x80ee, 0xbeef, "VirtualBox Graphics Adapter"),
+];
+
+#[derive(Debug, Default)]
+pub struct PciNames {
+    pub vendor: Option<String>,
+    pub device: Option<String>,
+    pub subsystem: Option<String>,
+}
+
+pub fn lookup(vendor: u16, device: u16, subsystem: Option<(u16, u16)>) -> PciNames {
+    let mut names = PCI_IDS_PATHS
+        .iter()
+        .find_map(|path| fs::read_to_string(path).ok())
+        .map(|content| lookup_in(&content, vendor, device, subsystem))
+        .unwrap_or_default();
+
+    if names.vendor.is_none() {
+        names.vendor = EMBEDDED_VENDORS
+            .iter()
+            .find(|(id, _)| *id == vendor)
+            .map(|(_, name)| name.to_string());
+    }
+
+    if names.device.is_none() {
+        names.device = EMBEDDED_DEVICES
+            .iter()
+            .find(|(vendor_id, device_id, _)| *vendor_id == vendor && *device_id == device)
+            .map(|(_, _, name)| name.to_string());
+    }
+
+    names
+}
+
+fn lookup_in(content: &str, vendor: u16, device: u16, subsystem: Option<(u16, u16)>) -> PciNames {
+    let mut names = PciNames::default();
+    let mut in_vendor = false;
+    let mut in_device = false;
+
+    for line in content.lines() {
+        if line.starts_with('#') || line.is_empty() {
+            continue;
+        }
+
+        if line.starts_with("C ") {
+            break;
+        }
+
+        if let Some(subsystem_line) = line.strip_prefix("\t\t") {
+            if in_device
+                && let Some((ids, name)) = subsystem_line.split_once("  ")
+                && let Some((sub_vendor, sub_device)) = ids.split_once(' ')
+                && subsystem == Some((parse_hex(sub_vendor), parse_hex(sub_device)))
+            {
+                names.subsystem = Some(name.trim().to_string());
+                break;
+            }
+        } else if let Some(device_line) = line.strip_prefix('\t') {
+            if !in_vendor {
+                continue;
+            }
+
+            if in_device {
+                break;
+            }
+
+            if let Some((id, name)) = device_line.split_once("  ")
+                && parse_hex(id) == device
+            {
+                names.device = Some(name.trim().to_string());
+                in_device = true;
+            }
+        } else {
+            if in_vendor {
+                break;
+            }
+
+            if let Some((id, name)) = line.split_once("  ")
+                && parse_hex(id) == vendor
+            {
+                names.vendor = Some(name.trim().to_string());
+                in_vendor = true;
+            }
+        }
+    }
+
+    names
+}
+
+pub fn parse_hex(value: &str) -> u16 {
+    u16::from_str_radix(value.trim().trim_start_matches("0x"), 16).unwrap_or(0)
+}
@@ -0,0 +1,205 @@
+use std::fs;
+
+use anyhow::{Context, Result, bail};
+
+static DMI_TABLE_PATH: &str = "/sys/firmware/dmi/tables/DMI";
+
+static PLACEHOLDER_STRINGS: &[&str] = &[
+    "",
+    "Unknown",
+    "Not Specified",
+    "Not Available",
+    "Not Applicable",
+    "To Be Filled By O.E.M.",
+    "Default string",
+    "None",
+    "NO DIMM",
+    "System manufacturer",
+    "System Product Name",
+    "System Version",
+    "Type1ProductConfigId",
+    "0123456789",
+];
+
+#[derive(Debug)]
+pub struct Structure<'a> {
+    pub kind: u8,
+    pub handle: u16,
+    data: &'a [u8],
+    strings: Vec<&'a [u8]>,
+}
+
+pub fn read_table() -> Result<Vec<u8>> {
+    let table = fs::read(DMI_TABLE_PATH)
+        .with_context(|| format!("smbios: read_table() - Error reading {}", DMI_TABLE_PATH))?;
+
+    if table.is_empty() {
+        bail!("smbios: read_table() - {} is empty", DMI_TABLE_PATH);
+    }
+
+    Ok(table)
+}
+
+pub fn structures(table: &[u8]) -> Vec<Structure<'_>> {
+    let mut structures: Vec<Structure> = Vec::new();
+    let mut offset = 0;
+
+    while offset + 4 <= table.len() {
+        let kind = table[offset];
+        let length = table[offset + 1] as usize;
+
+        if length < 4 || offset + length > table.len() {
+            break;
+        }
+
+        let data = &table[offset..offset + length];
+        let handle = u16::from_le_bytes([data[2], data[3]]);
+
+        let strings_start = offset + length;
+        let Some(strings_len) = table[strings_start..]
+            .windows(2)
+            .position(|pair| pair == [0, 0])
+        else {
+            break;
+        };
+
+        let strings = table[strings_start..strings_start + strings_len]
+            .split(|byte| *byte == 0)
+            .filter(|string| !string.is_empty())
+            .collect();
+
+        structures.push(Structure {
+            kind,
+            handle,
+            data,
+            strings,
+        });
+
+        if kind == 127 {
+            break;
+        }
+
+        offset = strings_start + strings_len + 2;
+    }
+
+    structures
+}
+
+impl Structure<'_> {
+    pub fn byte(&self, offset: usize) -> Option<u8> {
+        self.data.get(offset).copied()
+    }
+
+    pub fn word(&self, offset: usize) -> Option<u16> {
+        let bytes = self.data.get(offset..offset + 2)?;
+        Some(u16::from_le_bytes(bytes.try_into().ok()?))
+    }
+
+    pub fn dword(&self, offset: usize) -> Option<u32> {
+        let bytes = self.data.get(offset..offset + 4)?;
+        Some(u32::from_le_bytes(bytes.try_into().ok()?))
+    }
+
+    pub fn qword(&self, offset: usize) -> Option<u64> {
+        let bytes = self.data.get(offset..offset + 8)?;
+        Some(u64::from_le_bytes(bytes.try_into().ok()?))
+    }
+
+    pub fn string(&self, offset: usize) -> Option<String> {
+        let index = self.byte(offset)? as usize;
+        let string = self.strings.get(index.checked_sub(1)?)?;
+
+        clean_string(&String::from_utf8_lossy(string))
+    }
+}
+
+pub fn clean_string(value: &str) -> Option<String> {
+    let value = value.trim();
+
+    match PLACEHOLDER_STRINGS
+        .iter()
+        .any(|placeholder| placeholder.eq_ignore_ascii_case(value))
+    {
+        true => None,
+        false => Some(value.to_string()),
+    }
+}
+
+#[cfg(test)]
+pub fn encode_structure(
+    kind: u8,
+    handle: u16,
+    length: usize,
+    fields: &[(usize, &[u8])],
+    strings: &[&str],
+) -> Vec<u8> {
+    let mut data = vec![0u8; length];
+    data[0] = kind;
+    data[1] = length as u8;
+    data[2..4].copy_from_slice(&handle.to_le_bytes());
+
+    for (offset, bytes) in fields {
+        data[*offset..*offset + bytes.len()].copy_from_slice(bytes);
+    }
+
+    for string in strings {
+        data.extend_from_slice(string.as_bytes());
+        data.push(0);
+    }
+
+    if strings.is_empty() {
+        data.push(0);
+    }
+
+    data.push(0);
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn walks_structures_and_strings() {
+        let mut table =
+            encode_structure(1, 0x0001, 0x08, &[(0x04, &[1, 2])], &["Vendor", "Product"]);
+        table.extend(encode_structure(3, 0x0003, 0x06, &[(0x04, &[0])], &[]));
+        table.extend(encode_structure(127, 0xfeff, 0x04, &[], &[]));
+        table.extend(encode_structure(1, 0x0099, 0x08, &[], &[]));
+
+        let structures = structures(&table);
+
+        assert_eq!(structures.len(), 3);
+        assert_eq!(structures[0].kind, 1);
+        assert_eq!(structures[0].string(0x04).as_deref(), Some("Vendor"));
+        assert_eq!(structures[0].string(0x05).as_deref(), Some("Product"));
+        assert_eq!(structures[0].string(0x06), None);
+        assert_eq!(structures[1].handle, 0x0003);
+        assert_eq!(structures[1].string(0x04), None);
+        assert_eq!(structures[2].kind, 127);
+    }
+
+    #[test]
+    fn stops_at_truncated_structure() {
+        let mut table = encode_structure(17, 0x0011, 0x28, &[], &["DIMM A1"]);
+        let second = encode_structure(17, 0x0012, 0x28, &[], &["DIMM B1"]);
+        table.extend_from_slice(&second[..0x20]);
+
+        let structures = structures(&table);
+
+        assert_eq!(structures.len(), 1);
+        assert_eq!(structures[0].handle, 0x0011);
+
+        let unterminated = &encode_structure(17, 0x0011, 0x28, &[], &["DIMM A1"])[..0x2c];
+        assert!(super::structures(unterminated).is_empty());
+        assert!(super::structures(&[17, 2, 0]).is_empty());
+    }
+
+    #[test]
+    fn clean_string_filters_placeholders() {
+        assert_eq!(clean_string("  Kingston "), Some("Kingston".to_string()));
+        assert_eq!(clean_string("To Be Filled By O.E.M."), None);
+        assert_eq!(clean_string("no dimm"), None);
+        assert_eq!(clean_string(""), None);
+    }
+}
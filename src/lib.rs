@@ -1,3 +1,5 @@
+pub mod config;
 pub mod hardware;
 pub mod print_module;
+pub mod report;
 pub mod system;
@@ -1,4 +1,12 @@
-use atlasfetch::print_module;
+use std::num::NonZeroU64;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use atlasfetch::config::{self, Config};
+use atlasfetch::print_module::logo::LogoChoice;
+use atlasfetch::print_module::theme::ColorMode;
+use atlasfetch::print_module::{self, OutputFormat, TextOptions, Units};
+use atlasfetch::report::{Module, Report};
 use clap::Parser;
 
 #[derive(Parser, Debug)]
@@ -6,25 +14,135 @@ use clap::Parser;
 struct Cli {
     #[arg(short, long)]
     debug: bool,
+
+    #[arg(short, long, value_enum)]
+    format: Option<OutputFormat>,
+
+    #[arg(short, long, value_enum, value_delimiter = ',')]
+    modules: Option<Vec<Module>>,
+
+    #[arg(short, long, value_enum, value_delimiter = ',')]
+    skip: Vec<Module>,
+
+    #[arg(short, long, value_enum)]
+    units: Option<Units>,
+
+    #[arg(short, long)]
+    config: Option<PathBuf>,
+
+    #[arg(short, long)]
+    logo: Option<String>,
+
+    #[arg(long)]
+    logo_file: Option<PathBuf>,
+
+    #[arg(long, value_enum)]
+    color: Option<ColorMode>,
+
+    #[arg(short, long)]
+    theme: Option<String>,
+
+    #[arg(short, long)]
+    width: Option<usize>,
+
+    #[arg(long)]
+    memory_detail: bool,
+
+    #[arg(long)]
+    show_virtual_disks: bool,
+
+    #[arg(long)]
+    show_all_mounts: bool,
+
+    #[arg(long, value_name = "MS", value_parser = clap::value_parser!(u64).range(1..))]
+    io_sample: Option<u64>,
+
+    #[arg(long)]
+    print_default_config: bool,
+}
+
+fn load_settings(cli: &Cli) -> Result<(Config, TextOptions)> {
+    let config = Config::load(cli.config.as_deref())?;
+
+    let logo = match (&cli.logo_file, &cli.logo) {
+        (Some(path), _) => Some(LogoChoice::from_file(path)?),
+        (None, Some(name)) => Some(LogoChoice::from_name(name)?),
+        (None, None) => None,
+    };
+
+    let mut options = config.text_options(logo, cli.theme.as_deref())?;
+
+    if let Some(units) = cli.units {
+        options.units = units;
+    }
+
+    if let Some(color) = cli.color {
+        options.color = color;
+    }
+
+    if let Some(width) = cli.width {
+        options.width = Some(width);
+    }
+
+    if cli.memory_detail {
+        options.memory_detail = true;
+    }
+
+    Ok((config, options))
 }
 
 fn main() {
     let cli = Cli::parse();
 
-    let results = [
-        print_module::parse_distro(),
-        print_module::print_battery(),
-        print_module::parse_gpu(),
-        print_module::parse_memory(),
-        print_module::print_cpu(),
-        print_module::parse_disk(),
-    ];
+    if cli.print_default_config {
+        print!("{}", config::DEFAULT_CONFIG);
+        return;
+    }
+
+    let (config, options) = match load_settings(&cli) {
+        Ok(settings) => settings,
+        Err(e) => {
+            eprintln!("{:#}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let format = cli.format.or(config.format).unwrap_or(OutputFormat::Text);
+
+    let skip = match cli.modules {
+        Some(_) => cli.skip,
+        None => config.skip.into_iter().chain(cli.skip).collect(),
+    };
+
+    let mut disk_options = config.disk;
+
+    if cli.show_virtual_disks {
+        disk_options.show_virtual = true;
+    }
+
+    if cli.show_all_mounts {
+        disk_options.show_all_mounts = true;
+    }
+
+    if let Some(interval) = cli.io_sample {
+        disk_options.io_sample_ms = NonZeroU64::new(interval);
+    }
+
+    let modules = Module::resolve(cli.modules.or(config.modules).as_deref(), &skip);
+
+    let report = Report::collect_modules(&modules, &disk_options);
+
+    match print_module::render(&report, format, &options) {
+        Ok(output) => println!("{}", output),
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
 
     if cli.debug {
-        for result in results {
-            if let Err(e) = result {
-                eprintln!("\n{}", e);
-            }
+        for e in report.errors() {
+            eprintln!("\n{}", e);
         }
     }
 }
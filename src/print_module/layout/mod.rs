@@ -0,0 +1,211 @@
+use std::env;
+
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::print_module::theme::Style;
+
+const INDENT: &str = "    ";
+const GAP: &str = "    ";
+const FRAME_WIDTH: usize = 16;
+const MIN_VALUE_WIDTH: usize = 16;
+const MIN_LABEL_WIDTH: usize = 4;
+const MAX_LABEL_WIDTH: usize = 24;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Layout {
+    pub label_width: usize,
+    pub value_width: usize,
+}
+
+pub fn terminal_width() -> Option<usize> {
+    let mut size = libc::winsize {
+        ws_row: 0,
+        ws_col: 0,
+        ws_xpixel: 0,
+        ws_ypixel: 0,
+    };
+
+    let result = unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) };
+
+    if result == 0 && size.ws_col > 0 {
+        return Some(size.ws_col as usize);
+    }
+
+    env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.trim().parse::<usize>().ok())
+        .filter(|columns| *columns > 0)
+}
+
+pub fn display_width(text: &str) -> usize {
+    text.width()
+}
+
+pub fn truncate(text: &str, width: usize) -> String {
+    if display_width(text) <= width {
+        return text.to_string();
+    }
+
+    if width == 0 {
+        return String::new();
+    }
+
+    let mut truncated = String::new();
+    let mut used = 0;
+
+    for c in text.chars() {
+        let char_width = c.width().unwrap_or(0);
+
+        if used + char_width > width - 1 {
+            break;
+        }
+
+        truncated.push(c);
+        used += char_width;
+    }
+
+    truncated.push('…');
+    truncated
+}
+
+impl Layout {
+    pub fn new(boxes: &[Vec<(String, String)>], available: Option<usize>) -> Self {
+        let Self {
+            mut label_width,
+            mut value_width,
+        } = Self::natural(boxes);
+
+        if let Some(available) = available {
+            let room = available.saturating_sub(FRAME_WIDTH);
+
+            if label_width + value_width > room {
+                value_width = room
+                    .saturating_sub(label_width)
+                    .max(value_width.min(MIN_VALUE_WIDTH));
+            }
+
+            if label_width + value_width > room {
+                label_width = room
+                    .saturating_sub(value_width)
+                    .max(label_width.min(MIN_LABEL_WIDTH));
+            }
+
+            if label_width + value_width > room {
+                value_width = room.saturating_sub(label_width).max(1);
+            }
+
+            if label_width + value_width > room {
+                label_width = room.saturating_sub(value_width);
+            }
+        }
+
+        Self {
+            label_width,
+            value_width,
+        }
+    }
+
+    pub fn min_width(boxes: &[Vec<(String, String)>]) -> usize {
+        let natural = Self::natural(boxes);
+
+        natural.label_width + natural.value_width.min(MIN_VALUE_WIDTH) + FRAME_WIDTH
+    }
+
+    fn natural(boxes: &[Vec<(String, String)>]) -> Self {
+        let lines = boxes.iter().flatten();
+
+        Self {
+            label_width: lines
+                .clone()
+                .map(|(label, _)| display_width(label))
+                .max()
+                .unwrap_or(0)
+                .min(MAX_LABEL_WIDTH),
+            value_width: lines
+                .map(|(_, value)| display_width(value))
+                .max()
+                .unwrap_or(0),
+        }
+    }
+
+    fn border(&self, left: char, right: char, style: &Style) -> String {
+        format!(
+            "{}{}{}",
+            INDENT,
+            style.border(&format!(
+                "{}{}{}",
+                left,
+                "─".repeat(self.label_width + 2),
+                right
+            )),
+            style.border(&format!(
+                "{}{}{}{}",
+                GAP,
+                left,
+                "─".repeat(self.value_width + 2),
+                right
+            ))
+        )
+    }
+
+    fn line(&self, label: &str, value: &str, style: &Style) -> String {
+        let label = truncate(label, self.label_width);
+        let value = truncate(value, self.value_width);
+
+        format!(
+            "{}{} {}{} {}{}{} {}{} {}",
+            INDENT,
+            style.border("│"),
+            style.label(&label),
+            " ".repeat(self.label_width - display_width(&label)),
+            style.border("│"),
+            GAP,
+            style.border("│"),
+            style.value(&value),
+            " ".repeat(self.value_width - display_width(&value)),
+            style.border("│"),
+        )
+    }
+
+    pub fn render_box(&self, lines: &[(String, String)], style: &Style) -> String {
+        let mut output = format!("\n{}\n", self.border('╭', '╮', style));
+
+        output.push_str(&self.line("", "", style));
+        output.push('\n');
+
+        for (label, value) in lines {
+            output.push_str(&self.line(label, value, style));
+            output.push('\n');
+        }
+
+        output.push_str(&self.line("", "", style));
+        output.push('\n');
+
+        output.push_str(&format!("{}\n", self.border('╰', '╯', style)));
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wide_characters_take_two_columns() {
+        assert_eq!(display_width("Tux"), 3);
+        assert_eq!(display_width("ペンギン"), 8);
+        assert_eq!(display_width("Größe"), 5);
+    }
+
+    #[test]
+    fn truncate_respects_display_width() {
+        assert_eq!(truncate("ペンギン", 8), "ペンギン");
+        assert_eq!(truncate("ペンギン", 6), "ペン…");
+        assert_eq!(truncate("ペンギン", 5), "ペン…");
+        assert_eq!(truncate("Samsung Odyssey", 8), "Samsung…");
+
+        for width in 1..8 {
+            assert!(display_width(&truncate("ペンギン", width)) <= width);
+        }
+    }
+}
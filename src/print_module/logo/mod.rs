@@ -0,0 +1,288 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+
+use crate::print_module::layout::display_width;
+use crate::print_module::theme::Style;
+use crate::system::os_release::OsRelease;
+
+static TAB_WIDTH: usize = 8;
+
+static ARCH: &str = r"
+      /\
+     /  \
+    /\   \
+   /      \
+  /   ,,   \
+ /   |  |  -\
+/_-''    ''-_\";
+
+static DEBIAN: &str = r"
+  _____
+ /  __ \
+|  /    |
+|  \___-
+-_
+  --_";
+
+static UBUNTU: &str = r"
+         _
+     ---(_)
+ _/  ---  \
+(_) |   |
+  \  --- _/
+     ---(_)";
+
+static FEDORA: &str = r"
+        ,'''''.
+       |   ,.  |
+       |  |  '_'
+  ,....|  |..
+.'  ,_;|   ..'
+|  |   |  |
+|  ',_,'  |
+ '.     ,'
+   '''''";
+
+static MANJARO: &str = r"
+||||||||| ||||
+||||||||| ||||
+||||      ||||
+|||| |||| ||||
+|||| |||| ||||
+|||| |||| ||||
+|||| |||| ||||";
+
+static MINT: &str = r"
+ ___________
+|_          \
+  | | _____ |
+  | | | | | |
+  | | | | | |
+  | \_____/ |
+  \_________/";
+
+static OPENSUSE: &str = r"
+  _______
+__|   __ \
+     / .\ \
+     \__/ |
+   _______|
+   \_______
+__________/";
+
+static GENTOO: &str = r"
+ _-----_
+(       \
+\    0   \
+ \        )
+ /      _/
+(     _-
+\____-";
+
+static NIXOS: &str = r"
+  \\  \\ //
+ ==\\__\\/ //
+   //   \\//
+==//     //==
+ //\\___//
+// /\\  \\==
+  // \\  \\";
+
+static VOID: &str = r"
+    _______
+ _ \______ -
+| \  ___  \ |
+| | /   \ | |
+| | \___/ | |
+| \______ \_|
+ -_______\";
+
+static ALPINE: &str = r"
+   /\ /\
+  // \  \
+ //   \  \
+///    \  \
+//      \  \
+         \";
+
+static TUX: &str = r"
+    ___
+   (.. |
+   (<> |
+  / __  \
+ ( /  \ /|
+_/\ __)/_)
+\/-____\/";
+
+pub static NAMES: [&str; 12] = [
+    "arch",
+    "debian",
+    "ubuntu",
+    "fedora",
+    "manjaro",
+    "linuxmint",
+    "opensuse",
+    "gentoo",
+    "nixos",
+    "void",
+    "alpine",
+    "tux",
+];
+
+#[derive(Debug, Clone, Default)]
+pub enum LogoChoice {
+    #[default]
+    Auto,
+    Off,
+    Named(&'static str),
+    Custom(Vec<String>),
+}
+
+impl LogoChoice {
+    pub fn from_name(name: &str) -> Result<Self> {
+        match name {
+            "auto" => Ok(LogoChoice::Auto),
+            "none" | "off" => Ok(LogoChoice::Off),
+            _ => match find(name) {
+                Some(logo) => Ok(LogoChoice::Named(logo)),
+                None => bail!(
+                    "logo: LogoChoice::from_name() - Unknown logo \"{}\" (available: auto, none, {})",
+                    name,
+                    NAMES.join(", ")
+                ),
+            },
+        }
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let path = expand_home(path);
+
+        let content = fs::read_to_string(&path).with_context(|| {
+            format!(
+                "logo: LogoChoice::from_file() - Error reading {}",
+                path.display()
+            )
+        })?;
+
+        Ok(LogoChoice::Custom(
+            content.lines().map(expand_tabs).collect(),
+        ))
+    }
+
+    pub fn lines(&self, os_release: &OsRelease) -> Option<Vec<String>> {
+        let logo = match self {
+            LogoChoice::Off => return None,
+            LogoChoice::Custom(lines) => return Some(lines.clone()),
+            LogoChoice::Named(logo) => logo,
+            LogoChoice::Auto => os_release.ids().find_map(find).unwrap_or(TUX),
+        };
+
+        Some(logo.lines().skip(1).map(|line| line.to_string()).collect())
+    }
+}
+
+fn find(name: &str) -> Option<&'static str> {
+    let name = name.to_lowercase();
+
+    let logo = match name.as_str() {
+        "arch" | "archlinux" => ARCH,
+        "debian" => DEBIAN,
+        "ubuntu" => UBUNTU,
+        "fedora" => FEDORA,
+        "manjaro" => MANJARO,
+        "linuxmint" | "mint" => MINT,
+        "gentoo" => GENTOO,
+        "nixos" => NIXOS,
+        "void" => VOID,
+        "alpine" => ALPINE,
+        "tux" | "linux" => TUX,
+        _ if name.starts_with("opensuse") || name == "suse" => OPENSUSE,
+        _ => return None,
+    };
+
+    Some(logo)
+}
+
+fn expand_tabs(line: &str) -> String {
+    let mut expanded = String::new();
+
+    for c in line.chars() {
+        match c {
+            '\t' => {
+                let column = display_width(&expanded);
+                expanded.push_str(&" ".repeat(TAB_WIDTH - column % TAB_WIDTH));
+            }
+            _ => expanded.push(c),
+        }
+    }
+
+    expanded
+}
+
+fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), env::var_os("HOME")) {
+        (Ok(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => path.to_path_buf(),
+    }
+}
+
+pub fn width(logo: &[String]) -> usize {
+    logo.iter()
+        .map(|line| display_width(line))
+        .max()
+        .unwrap_or(0)
+}
+
+pub fn attach(text: &str, logo: &[String], style: &Style) -> String {
+    let width = width(logo);
+
+    let text_lines: Vec<&str> = text.lines().collect();
+    let height = text_lines.len().max(logo.len() + 1);
+
+    let mut output = String::new();
+
+    for i in 0..height {
+        let logo_line = match i {
+            0 => "",
+            _ => logo.get(i - 1).map_or("", |line| line.as_str()),
+        };
+        let text_line = text_lines.get(i).copied().unwrap_or("");
+
+        let padding = width - display_width(logo_line);
+        let line = format!(
+            "  {}{}  {}",
+            style.logo(logo_line),
+            " ".repeat(padding),
+            text_line
+        );
+
+        output.push_str(line.trim_end());
+        output.push('\n');
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_logo_tabs_are_expanded() {
+        let path = env::temp_dir().join(format!("atlasfetch-logo-{}.txt", std::process::id()));
+        fs::write(&path, "\t/\\\nペン\t||\n  ab\tc\n").unwrap();
+
+        let logo = LogoChoice::from_file(&path);
+        fs::remove_file(&path).unwrap();
+
+        let LogoChoice::Custom(lines) = logo.unwrap() else {
+            panic!("expected a custom logo");
+        };
+
+        assert_eq!(lines, vec!["        /\\", "ペン    ||", "  ab    c"]);
+        assert_eq!(width(&lines), 10);
+    }
+}
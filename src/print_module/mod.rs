@@ -1,230 +1,999 @@
+pub mod layout;
+pub mod logo;
+pub mod theme;
+
 use crate::hardware::{
-    battery::BatteryInfo, cpu::CpuInfo, disk::DiskInfo, gpu::GpuInfo, memory::MemoryInfo,
+    battery::{BatteryInfo, CapacityUnit},
+    board::BoardInfo,
+    cpu::CpuInfo,
+    disk::{
+        DiskInfo,
+        btrfs::{BtrfsAllocation, BtrfsInfo},
+        iostat::DiskIo,
+        tree::{BlockNode, Filesystem, NodeKind},
+    },
+    display::DisplayInfo,
+    gpu::GpuInfo,
+    memory::{MemoryDetail, MemoryInfo, dimm::DimmInfo},
+};
+use crate::report::{Module, Report};
+use crate::system::{
+    desktop::DesktopInfo, distro::DistroInfo, os_release::OsRelease, packages::PackagesInfo,
 };
-use crate::system::distro::DistroInfo;
 
-use anyhow::Result;
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use serde::Deserialize;
 
-static VERTICAL_1: &str =
-    "\t╭──────────────────────╮    ╭─────────────────────────────────────────────────────────────╮";
-static VERTICAL_2: &str =
-    "\t╰──────────────────────╯    ╰─────────────────────────────────────────────────────────────╯";
+use layout::Layout;
+use logo::LogoChoice;
+use theme::{ColorMode, Style, Theme};
 
-fn format_line(label: &str, value: &str) -> String {
-    format!("\t    {:<29}{}", label, value)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    Text,
+    Json,
 }
 
-pub fn print_battery() -> Result<()> {
-    let battery_info: Vec<BatteryInfo> = match BatteryInfo::get_bats() {
-        Ok(value) => value,
-        Err(error) => return Err(error),
-    };
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Units {
+    #[default]
+    Binary,
+    Decimal,
+}
 
-    for battery in &battery_info {
-        println!("\n{}", VERTICAL_1);
-        println!();
+pub static LABELS: &[&str] = &[
+    "os",
+    "kernel",
+    "uptime",
+    "os_installed",
+    "shell",
+    "host",
+    "board",
+    "bios",
+    "packages",
+    "desktop",
+    "window_manager",
+    "session",
+    "battery",
+    "gpu",
+    "display",
+    "ram",
+    "memory_slots",
+    "swap",
+    "zram",
+    "zswap",
+    "buffers",
+    "cached",
+    "shmem",
+    "slab",
+    "dirty",
+    "anon_huge_pages",
+    "huge_pages",
+    "committed",
+    "cpu_model",
+    "cpu_cache",
+    "disk",
+    "disk_io",
+    "mounts",
+];
+
+#[derive(Debug, Default)]
+pub struct TextOptions {
+    pub labels: HashMap<String, String>,
+    pub units: Units,
+    pub logo: LogoChoice,
+    pub color: ColorMode,
+    pub theme: Theme,
+    pub width: Option<usize>,
+    pub memory_detail: bool,
+}
 
-        println!(
-            "{}",
-            format_line(
-                &format!("Battery [{}]", battery.bat_name),
-                &format!("Model: {}", battery.model)
-            )
-        );
+impl TextOptions {
+    fn label(&self, key: &str, default: &str) -> String {
+        self.labels
+            .get(key)
+            .cloned()
+            .unwrap_or_else(|| default.to_string())
+    }
 
-        println!(
-            "{}",
-            format_line(
-                "",
-                &format!("Status: {:?} [{}%]", battery.status, battery.power_level)
-            )
-        );
+    fn size(&self, gib: f64) -> String {
+        match self.units {
+            Units::Binary => format!("{} GiB", gib),
+            Units::Decimal => format!("{} GB", (gib * 1.073_741_824 * 100.0).trunc() / 100.0),
+        }
+    }
+
+    fn bytes(&self, bytes: u64) -> String {
+        let gib = bytes as f64 / 1024.0 / 1024.0 / 1024.0;
+
+        self.size((gib * 100.0).trunc() / 100.0)
+    }
+
+    fn kib_size(&self, kib: u64) -> String {
+        let (base, units) = match self.units {
+            Units::Binary => (1024.0, ["KiB", "MiB", "GiB", "TiB"]),
+            Units::Decimal => (1000.0, ["kB", "MB", "GB", "TB"]),
+        };
+
+        let mut value = match self.units {
+            Units::Binary => kib as f64,
+            Units::Decimal => kib as f64 * 1.024,
+        };
+        let mut unit = 0;
+
+        while value >= base && unit < units.len() - 1 {
+            value /= base;
+            unit += 1;
+        }
+
+        format!("{} {}", (value * 100.0).trunc() / 100.0, units[unit])
+    }
+
+    fn cache_size(&self, kib: u32) -> String {
+        match self.units {
+            Units::Binary => format!("{} KiB", kib),
+            Units::Decimal => format!("{} kB", kib as u64 * 1024 / 1000),
+        }
+    }
+}
+
+fn battery_lines(battery: &BatteryInfo, options: &TextOptions) -> Vec<(String, String)> {
+    let mut model = format!("Model: {}", battery.model);
+
+    let details: Vec<&str> = [&battery.manufacturer, &battery.technology]
+        .into_iter()
+        .flatten()
+        .map(String::as_str)
+        .collect();
+
+    if !details.is_empty() {
+        model.push_str(&format!(" ({})", details.join(", ")));
+    }
+
+    if let Some(serial_number) = &battery.serial_number {
+        model.push_str(&format!(" [{}]", serial_number));
+    }
+
+    let mut lines = vec![
+        (
+            format!(
+                "{} [{}]",
+                options.label("battery", "Battery"),
+                battery.bat_name
+            ),
+            model,
+        ),
+        (
+            String::new(),
+            format!("Status: {:?} [{}%]", battery.status, battery.power_level),
+        ),
+    ];
+
+    if let Some(capacity) = &battery.capacity {
+        let unit = match capacity.unit {
+            CapacityUnit::WattHours => "Wh",
+            CapacityUnit::AmpHours => "Ah",
+        };
+
+        let mut health = format!("{:.2}/{:.2} {}", capacity.full, capacity.design, unit);
+
+        if let Some(percent) = battery.health {
+            health = format!("{}% ({})", percent, health);
+        }
+
+        if let Some(cycle_count) = battery.cycle_count {
+            health.push_str(&format!(", {} cycles", cycle_count));
+        }
+
+        lines.push((String::new(), format!("Health: {}", health)));
+    } else if let Some(cycle_count) = battery.cycle_count {
+        lines.push((String::new(), format!("Health: {} cycles", cycle_count)));
+    }
 
-        println!("\n{}", VERTICAL_2);
+    if let Some(voltage) = battery.voltage {
+        lines.push((String::new(), format!("Voltage: {:.2} V", voltage)));
     }
 
-    Ok(())
+    lines
 }
 
-pub fn print_cpu() -> Result<()> {
-    let cpu_info: CpuInfo = match CpuInfo::new() {
-        Ok(value) => value,
-        Err(error) => return Err(error),
+fn cpu_lines(cpu_info: &CpuInfo, options: &TextOptions) -> Vec<(String, String)> {
+    let mut lines = vec![(
+        options.label("cpu_model", "CPU Model"),
+        format!(
+            "{} [{}] {} @ GHz",
+            cpu_info.model_name, cpu_info.siblings_count, cpu_info.max_frequency
+        ),
+    )];
+
+    for cache_info in &cpu_info.cache_list {
+        lines.push((
+            options.label("cpu_cache", "CPU Cache"),
+            format!(
+                "L{}  {}  ({:?})",
+                cache_info.level,
+                options.cache_size(cache_info.size),
+                cache_info.cache_type
+            ),
+        ));
+    }
+
+    lines
+}
+
+fn disk_lines(disk_info: &DiskInfo, options: &TextOptions) -> Vec<(String, String)> {
+    let mut lines: Vec<(String, String)> = Vec::new();
+
+    for (i, disk) in disk_info.disks.iter().enumerate() {
+        let label = match i {
+            0 => options.label("disk", "Disk"),
+            _ => String::new(),
+        };
+
+        let mut value = disk.name.clone();
+
+        for field in [&disk.vendor, &disk.model].into_iter().flatten() {
+            value.push_str(&format!(" {}", field));
+        }
+
+        value.push_str(&format!(
+            " {} ({:?}{})",
+            options.kib_size(disk.size_bytes / 1024),
+            disk.kind,
+            if disk.removable { ", removable" } else { "" }
+        ));
+
+        if let Some(serial) = &disk.serial {
+            value.push_str(&format!(" [{}]", serial));
+        }
+
+        value.push_str(&filesystem_text(&disk.filesystem));
+
+        lines.push((label, value));
+
+        block_tree_lines(&disk.children, "", options, &mut lines);
+    }
+
+    for (i, io) in disk_info.io.iter().flatten().enumerate() {
+        let label = match i {
+            0 => options.label("disk_io", "I/O"),
+            _ => String::new(),
+        };
+
+        lines.push((label, disk_io_text(io, options)));
+    }
+
+    for (i, part_info) in disk_info.partitions.iter().enumerate() {
+        let label = match i {
+            0 => options.label("mounts", "Mounts"),
+            _ => String::new(),
+        };
+
+        lines.push((
+            label,
+            format!(
+                "{} {} {}% {}/{} ({} free)",
+                part_info.mount_point,
+                usage_bar(part_info.used_percent),
+                part_info.used_percent,
+                options.kib_size(part_info.used_bytes / 1024),
+                options.kib_size(part_info.total_bytes / 1024),
+                options.kib_size(part_info.available_bytes / 1024)
+            ),
+        ));
+
+        let mut detail = format!("  {} {}", part_info.name, part_info.file_system);
+
+        if let Some(inodes_percent) = part_info.inodes_percent() {
+            detail.push_str(&format!(", inodes {}%", inodes_percent));
+        }
+
+        if !part_info.mount_options.is_empty() {
+            detail.push_str(&format!(", {}", part_info.mount_options.join(",")));
+        }
+
+        lines.push((String::new(), detail));
+
+        if !part_info.other_mount_points.is_empty() {
+            lines.push((
+                String::new(),
+                format!("  also at {}", part_info.other_mount_points.join(", ")),
+            ));
+        }
+
+        if let Some(btrfs) = &part_info.btrfs {
+            lines.extend(btrfs_lines(btrfs, options));
+        }
+
+        if let Some(zfs) = &part_info.zfs {
+            let mut value = format!("  zfs pool {} {}", zfs.name, zfs.state);
+
+            if let Some(dataset) = &zfs.dataset {
+                value.push_str(&format!(
+                    ", {} read, {} written",
+                    options.kib_size(dataset.read_bytes / 1024),
+                    options.kib_size(dataset.written_bytes / 1024)
+                ));
+            }
+
+            lines.push((String::new(), value));
+        }
+    }
+
+    lines
+}
+
+fn disk_io_text(io: &DiskIo, options: &TextOptions) -> String {
+    let direction = |name: &str, bytes_per_sec: f64, iops: f64, latency_ms: Option<f64>| {
+        let mut value = format!(
+            "{} {}/s {:.0} IOPS",
+            name,
+            options.kib_size(bytes_per_sec as u64 / 1024),
+            iops
+        );
+
+        if let Some(latency_ms) = latency_ms {
+            value.push_str(&format!(" {:.2} ms", latency_ms));
+        }
+
+        value
     };
-    let cache_vec = cpu_info.cache_list;
-
-    println!("\n{}", VERTICAL_1);
-    println!();
-
-    println!(
-        "{}",
-        format_line(
-            "CPU Model",
-            &format!(
-                "{} [{}] {} @ GHz",
-                cpu_info.model_name, cpu_info.siblings_count, cpu_info.max_frequency
-            )
+
+    format!(
+        "{} {}, {}, queue {:.2}, util {:.0}%",
+        io.name,
+        direction(
+            "read",
+            io.read_bytes_per_sec,
+            io.read_iops,
+            io.read_latency_ms
+        ),
+        direction(
+            "write",
+            io.write_bytes_per_sec,
+            io.write_iops,
+            io.write_latency_ms
+        ),
+        io.queue_depth,
+        io.utilization
+    )
+}
+
+fn btrfs_lines(btrfs: &BtrfsInfo, options: &TextOptions) -> Vec<(String, String)> {
+    let allocation = |name: &str, allocation: &BtrfsAllocation| {
+        format!(
+            "{} {}/{} ({})",
+            name,
+            options.kib_size(allocation.used_bytes / 1024),
+            options.kib_size(allocation.total_bytes / 1024),
+            allocation.profiles.join("/")
         )
-    );
-
-    for cache_info in &cache_vec {
-        println!(
-            "{}",
-            format_line(
-                "CPU Cache",
-                &format!(
-                    "L{}  {} KiB  ({:?})",
-                    cache_info.level, cache_info.size, cache_info.cache_type
-                )
-            )
+    };
+
+    vec![
+        (
+            String::new(),
+            format!(
+                "  btrfs on {}, {} free (estimated), {} unallocated",
+                btrfs.devices.join(", "),
+                options.kib_size(btrfs.free_estimate_bytes / 1024),
+                options.kib_size(btrfs.unallocated_bytes / 1024)
+            ),
+        ),
+        (
+            String::new(),
+            format!(
+                "  {}, {}, {}",
+                allocation("data", &btrfs.data),
+                allocation("metadata", &btrfs.metadata),
+                allocation("system", &btrfs.system)
+            ),
+        ),
+    ]
+}
+
+fn usage_bar(percent: u8) -> String {
+    let filled = (percent as usize).div_ceil(10).min(10);
+
+    format!("[{}{}]", "█".repeat(filled), "░".repeat(10 - filled))
+}
+
+fn block_tree_lines(
+    nodes: &[BlockNode],
+    prefix: &str,
+    options: &TextOptions,
+    lines: &mut Vec<(String, String)>,
+) {
+    for (i, node) in nodes.iter().enumerate() {
+        let last = i + 1 == nodes.len();
+        let branch = if last { "└─" } else { "├─" };
+
+        let mut value = format!(
+            "{}{}{}",
+            prefix,
+            branch,
+            node.mapper_name.as_deref().unwrap_or(&node.name)
         );
+
+        if node.kind != NodeKind::Partition {
+            value.push_str(&format!(" ({:?})", node.kind));
+        }
+
+        value.push_str(&format!(" {}", options.kib_size(node.size_bytes / 1024)));
+        value.push_str(&filesystem_text(&node.filesystem));
+
+        lines.push((String::new(), value));
+
+        let child_prefix = format!("{}{}", prefix, if last { "  " } else { "│ " });
+        block_tree_lines(&node.children, &child_prefix, options, lines);
     }
+}
 
-    println!("\n{}", VERTICAL_2);
+fn filesystem_text(filesystem: &Filesystem) -> String {
+    let mut text = String::new();
 
-    Ok(())
+    if let Some(fs_type) = &filesystem.fs_type {
+        text.push_str(&format!(" {}", fs_type));
+    }
+
+    match (&filesystem.label, &filesystem.uuid) {
+        (Some(label), _) => text.push_str(&format!(" \"{}\"", label)),
+        (None, Some(uuid)) => text.push_str(&format!(" UUID={}", uuid)),
+        (None, None) => {}
+    }
+
+    if !filesystem.mount_points.is_empty() {
+        text.push_str(&format!(" -> {}", filesystem.mount_points.join(", ")));
+    }
+
+    text
 }
 
-pub fn parse_disk() -> Result<()> {
-    let disk_info: DiskInfo = match DiskInfo::new() {
-        Ok(value) => value,
-        Err(error) => return Err(error),
-    };
+fn gpu_lines(gpus: &[GpuInfo], options: &TextOptions) -> Vec<(String, String)> {
+    let mut lines: Vec<(String, String)> = Vec::new();
+
+    for (i, gpu_info) in gpus.iter().enumerate() {
+        let label = match i {
+            0 => options.label("gpu", "GPU"),
+            _ => String::new(),
+        };
+
+        let role = match gpu_info.boot_vga {
+            true => format!("{:?}, primary", gpu_info.kind),
+            false => format!("{:?}", gpu_info.kind),
+        };
+
+        lines.push((
+            label,
+            format!(
+                "{} {} ({})",
+                gpu_info.vendor_name, gpu_info.device_name, role
+            ),
+        ));
+
+        if let Some(driver) = &gpu_info.driver {
+            lines.push((String::new(), format!("Driver: {}", driver)));
+        }
+
+        match (gpu_info.vram_used, gpu_info.vram_total) {
+            (Some(used), Some(total)) => lines.push((
+                String::new(),
+                format!("VRAM: {}/{}", options.bytes(used), options.bytes(total)),
+            )),
+            (None, Some(total)) => {
+                lines.push((String::new(), format!("VRAM: {}", options.bytes(total))))
+            }
+            _ => {}
+        }
+
+        match (gpu_info.clocks.current_mhz, gpu_info.clocks.max_mhz) {
+            (Some(current), Some(max)) => {
+                lines.push((String::new(), format!("Clock: {}/{} MHz", current, max)))
+            }
+            (None, Some(max)) => lines.push((String::new(), format!("Clock: {} MHz max", max))),
+            _ => {}
+        }
+
+        if let Some(link) = &gpu_info.pcie_link {
+            let mut value = format!("PCIe: {} x{}", link.speed, link.width);
+
+            if let (Some(max_speed), Some(max_width)) = (&link.max_speed, link.max_width) {
+                value.push_str(&format!(" (max {} x{})", max_speed, max_width));
+            }
+
+            lines.push((String::new(), value));
+        }
+    }
 
-    let partition_vec = disk_info.partitions;
+    lines
+}
 
-    println!("\n{}", VERTICAL_1);
-    println!();
+fn display_lines(displays: &[DisplayInfo], options: &TextOptions) -> Vec<(String, String)> {
+    let mut lines: Vec<(String, String)> = Vec::new();
+
+    for (i, display_info) in displays.iter().enumerate() {
+        let label = match i {
+            0 => options.label("display", "Display"),
+            _ => String::new(),
+        };
+
+        let mut value = String::new();
+
+        if let Some(edid) = &display_info.edid {
+            let manufacturer = edid
+                .manufacturer
+                .as_deref()
+                .unwrap_or(&edid.manufacturer_id);
+
+            match &edid.model {
+                Some(model) => value.push_str(&format!("{} {} ", manufacturer, model)),
+                None => value.push_str(&format!("{} {:04x} ", manufacturer, edid.product_code)),
+            }
+        }
+
+        if let Some((width, height)) = display_info.resolution() {
+            value.push_str(&format!("{}x{}", width, height));
+        }
+
+        if let Some(refresh_rate) = display_info
+            .edid
+            .as_ref()
+            .and_then(|edid| edid.refresh_rate)
+        {
+            value.push_str(&format!(" @ {} Hz", refresh_rate));
+        }
+
+        if let Some(inches) = display_info
+            .edid
+            .as_ref()
+            .and_then(|edid| edid.diagonal_inches())
+        {
+            value.push_str(&format!(" {}\"", inches));
+        }
+
+        value.push_str(&format!(" [{}]", display_info.connector));
+
+        lines.push((label, value.trim_start().to_string()));
+    }
 
-    println!(
-        "{}",
-        format_line(
-            "Disk",
-            &format!(
-                "(/dev/{}) {} GiB/{} GiB",
-                disk_info.mount_point, disk_info.free_size, disk_info.total_size
-            )
-        )
-    );
-
-    for part_info in partition_vec {
-        println!(
-            "{}",
-            format_line(
-                "",
-                &format!(
-                    "({} -> {}) {} GiB ({})",
-                    part_info.name, part_info.mount_point, part_info.size, part_info.file_system
-                )
-            )
+    lines
+}
+
+fn memory_lines(memory_info: &MemoryInfo, options: &TextOptions) -> Vec<(String, String)> {
+    let mut lines = vec![(
+        options.label("ram", "RAM"),
+        format!(
+            "{}/{} ({})",
+            options.size(memory_info.used_size),
+            options.size(memory_info.total_size),
+            options.size(memory_info.free_size)
+        ),
+    )];
+
+    if let Some(dimms) = &memory_info.dimms {
+        lines.extend(dimm_lines(dimms, options));
+    }
+
+    let swap_info = &memory_info.swap_info;
+
+    if swap_info.total_size > 0.0 || !swap_info.devices.is_empty() {
+        lines.push((
+            options.label("swap", "Swap"),
+            format!(
+                "{}/{} ({})",
+                options.size(swap_info.used_size),
+                options.size(swap_info.total_size),
+                options.size(swap_info.free_size)
+            ),
+        ));
+
+        for device in &swap_info.devices {
+            lines.push((
+                String::new(),
+                format!(
+                    "{} {}/{} ({}, prio {})",
+                    device.name,
+                    options.size(device.used_size),
+                    options.size(device.total_size),
+                    device.kind,
+                    device.priority
+                ),
+            ));
+        }
+    }
+
+    for (i, zram) in swap_info.zram.iter().enumerate() {
+        let label = match i {
+            0 => options.label("zram", "Zram"),
+            _ => String::new(),
+        };
+
+        let mut value = format!(
+            "{} {} -> {}",
+            zram.name,
+            options.size(zram.original_size),
+            options.size(zram.compressed_size)
         );
+
+        if let Some(ratio) = zram.compression_ratio {
+            value.push_str(&format!(" ({}x)", ratio));
+        }
+
+        if let Some(algorithm) = &zram.algorithm {
+            value.push_str(&format!(" [{}]", algorithm));
+        }
+
+        lines.push((label, value));
     }
 
-    println!("\n{}\n", VERTICAL_2);
+    if let Some(zswap) = &swap_info.zswap
+        && zswap.enabled
+    {
+        let mut value: Vec<String> = [
+            zswap.compressor.clone(),
+            zswap.zpool.clone(),
+            zswap
+                .max_pool_percent
+                .map(|percent| format!("max {}%", percent)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        if let (Some(pool), Some(stored)) = (zswap.pool_size, zswap.stored_size) {
+            value.push(format!(
+                "{} -> {}",
+                options.size(stored),
+                options.size(pool)
+            ));
+        }
+
+        lines.push((options.label("zswap", "Zswap"), value.join(", ")));
+    }
 
-    Ok(())
+    if options.memory_detail {
+        lines.extend(memory_detail_lines(&memory_info.detail, options));
+    }
+
+    lines
 }
 
-pub fn parse_gpu() -> Result<()> {
-    let gpu_info: GpuInfo = match GpuInfo::new() {
-        Ok(value) => value,
-        Err(error) => return Err(error),
-    };
+fn dimm_lines(dimms: &DimmInfo, options: &TextOptions) -> Vec<(String, String)> {
+    let mut summary = format!("{}/{} populated", dimms.populated(), dimms.slot_count);
 
-    println!("\n{}", VERTICAL_1);
-    println!();
+    if let Some(max_capacity) = dimms.max_capacity_kib {
+        summary.push_str(&format!(", max {}", options.kib_size(max_capacity)));
+    }
 
-    println!(
-        "{}",
-        format_line(
-            "GPU",
-            &format!("{} {}", gpu_info.vendor_name, gpu_info.device_name)
-        )
-    );
+    let mut lines = vec![(options.label("memory_slots", "Slots"), summary)];
 
-    println!("\n{}", VERTICAL_2);
+    for device in &dimms.devices {
+        let locator = device.locator.as_deref().unwrap_or("?");
 
-    Ok(())
-}
+        let Some(size_kib) = device.size_kib else {
+            lines.push((String::new(), format!("{}: empty", locator)));
+            continue;
+        };
 
-pub fn parse_memory() -> Result<()> {
-    let memory_info: MemoryInfo = match MemoryInfo::new() {
-        Ok(value) => value,
-        Err(error) => return Err(error),
-    };
+        let mut value = format!(
+            "{}: {} {} {}",
+            locator,
+            options.kib_size(size_kib),
+            device.memory_type,
+            device.form_factor
+        );
 
-    println!("\n{}", VERTICAL_1);
-    println!();
-
-    println!(
-        "{}",
-        format_line(
-            "RAM",
-            &format!(
-                "{} GiB/{} GiB ({} GiB)",
-                memory_info.used_size, memory_info.total_size, memory_info.free_size
-            )
-        )
-    );
+        if let Some(speed) = device.configured_speed_mts.or(device.speed_mts) {
+            value.push_str(&format!(" {} MT/s", speed));
+        }
 
-    println!("\n{}", VERTICAL_2);
+        for field in [&device.manufacturer, &device.part_number]
+            .into_iter()
+            .flatten()
+        {
+            value.push_str(&format!(" {}", field));
+        }
 
-    Ok(())
+        lines.push((String::new(), value));
+    }
+
+    lines
 }
 
-pub fn parse_distro() -> Result<()> {
-    let distro_info: DistroInfo = match DistroInfo::new() {
-        Ok(value) => value,
-        Err(error) => return Err(error),
+fn memory_detail_lines(detail: &MemoryDetail, options: &TextOptions) -> Vec<(String, String)> {
+    let mut lines = vec![
+        (
+            options.label("buffers", "Buffers"),
+            options.kib_size(detail.buffers_kib),
+        ),
+        (
+            options.label("cached", "Cached"),
+            options.kib_size(detail.cached_kib),
+        ),
+        (
+            options.label("shmem", "Shmem"),
+            options.kib_size(detail.shmem_kib),
+        ),
+        (
+            options.label("slab", "Slab"),
+            format!(
+                "{} (reclaimable {}, unreclaimable {})",
+                options.kib_size(detail.slab_reclaimable_kib + detail.slab_unreclaimable_kib),
+                options.kib_size(detail.slab_reclaimable_kib),
+                options.kib_size(detail.slab_unreclaimable_kib)
+            ),
+        ),
+        (
+            options.label("dirty", "Dirty"),
+            format!(
+                "{} (writeback {})",
+                options.kib_size(detail.dirty_kib),
+                options.kib_size(detail.writeback_kib)
+            ),
+        ),
+        (
+            options.label("anon_huge_pages", "AnonHugePages"),
+            options.kib_size(detail.anon_huge_pages_kib),
+        ),
+    ];
+
+    for (i, pool) in detail.huge_pages.iter().enumerate() {
+        let label = match i {
+            0 => options.label("huge_pages", "HugeTLB"),
+            _ => String::new(),
+        };
+
+        lines.push((
+            label,
+            format!(
+                "{} pages: {}/{} used ({}), {} reserved, {} surplus",
+                options.kib_size(pool.page_size_kib),
+                pool.total_pages - pool.free_pages.min(pool.total_pages),
+                pool.total_pages,
+                options.kib_size(pool.total_pages * pool.page_size_kib),
+                pool.reserved_pages,
+                pool.surplus_pages
+            ),
+        ));
+    }
+
+    let commit_percent = match detail.commit_limit_kib {
+        0 => 0,
+        limit => detail.committed_kib * 100 / limit,
     };
 
-    println!("\n{}", VERTICAL_1);
-    println!();
-
-    println!(
-        "{}",
-        format_line(
-            "OS",
-            &format!(
-                "{} {} [{}]",
-                distro_info.name, distro_info.build_id, distro_info.arch
-            )
-        )
-    );
+    lines.push((
+        options.label("committed", "Committed"),
+        format!(
+            "{}/{} ({}% of CommitLimit)",
+            options.kib_size(detail.committed_kib),
+            options.kib_size(detail.commit_limit_kib),
+            commit_percent
+        ),
+    ));
+
+    lines
+}
 
-    println!(
-        "{}",
-        format_line(
-            "Kernel",
-            &format!(
+fn distro_lines(distro_info: &DistroInfo, options: &TextOptions) -> Vec<(String, String)> {
+    vec![
+        (
+            options.label("os", "OS"),
+            format!(
+                "{} [{}]",
+                distro_info.os_release.display_name(),
+                distro_info.arch
+            ),
+        ),
+        (
+            options.label("kernel", "Kernel"),
+            format!(
                 "{} {}",
                 distro_info.kernel_info.name, distro_info.kernel_info.version
-            )
-        )
-    );
-
-    println!(
-        "{}",
-        format_line(
-            "Uptime",
-            &format!(
+            ),
+        ),
+        (
+            options.label("uptime", "Uptime"),
+            format!(
                 "{} hours {} mins",
                 distro_info.uptime_info.hours, distro_info.uptime_info.minutes
-            )
-        )
-    );
+            ),
+        ),
+        (
+            options.label("os_installed", "OS Installed"),
+            distro_info.uptime_info.date_installation.clone(),
+        ),
+        (options.label("shell", "Shell"), distro_info.shell.clone()),
+    ]
+}
 
-    println!(
-        "{}",
-        format_line(
-            "OS Installed",
-            &distro_info.uptime_info.date_installation.to_string()
-        )
-    );
+fn packages_lines(packages_info: &PackagesInfo, options: &TextOptions) -> Vec<(String, String)> {
+    let mut lines: Vec<(String, String)> = Vec::new();
+
+    for (i, package_count) in packages_info.managers.iter().enumerate() {
+        let label = match i {
+            0 => options.label("packages", "Packages"),
+            _ => String::new(),
+        };
+
+        lines.push((
+            label,
+            format!("{} ({})", package_count.count, package_count.manager),
+        ));
+    }
 
-    println!("{}", format_line("Shell", &distro_info.shell));
+    lines
+}
+
+fn board_lines(board_info: &BoardInfo, options: &TextOptions) -> Vec<(String, String)> {
+    let join = |parts: &[&Option<String>]| -> Option<String> {
+        let parts: Vec<&str> = parts.iter().filter_map(|part| part.as_deref()).collect();
+
+        (!parts.is_empty()).then(|| parts.join(" "))
+    };
+
+    let mut lines: Vec<(String, String)> = Vec::new();
+
+    if let Some(mut host) = join(&[
+        &board_info.sys_vendor,
+        &board_info.product_name,
+        &board_info.product_version,
+    ]) {
+        if let Some(chassis_type) = &board_info.chassis_type {
+            host.push_str(&format!(" ({})", chassis_type));
+        }
+
+        lines.push((options.label("host", "Host"), host));
+    }
+
+    if let Some(board) = join(&[
+        &board_info.board_vendor,
+        &board_info.board_name,
+        &board_info.board_version,
+    ]) {
+        lines.push((options.label("board", "Board"), board));
+    }
 
-    println!("\n{}", VERTICAL_2);
+    if let Some(mut bios) = join(&[&board_info.bios_vendor, &board_info.bios_version]) {
+        if let Some(bios_date) = &board_info.bios_date {
+            bios.push_str(&format!(" ({})", bios_date));
+        }
+
+        lines.push((options.label("bios", "BIOS"), bios));
+    }
+
+    lines
+}
+
+fn desktop_lines(desktop_info: &DesktopInfo, options: &TextOptions) -> Vec<(String, String)> {
+    let mut lines: Vec<(String, String)> = Vec::new();
+
+    if let Some(desktop) = &desktop_info.desktop {
+        lines.push((options.label("desktop", "DE"), desktop.clone()));
+    }
+
+    if let Some(window_manager) = &desktop_info.window_manager {
+        lines.push((
+            options.label("window_manager", "WM"),
+            window_manager.clone(),
+        ));
+    }
 
-    Ok(())
+    let session_type = match &desktop_info.session {
+        Some(session) => format!("{:?} ({})", desktop_info.session_type, session),
+        None => format!("{:?}", desktop_info.session_type),
+    };
+
+    lines.push((options.label("session", "Session"), session_type));
+
+    lines
+}
+
+pub fn render_text(report: &Report, options: &TextOptions) -> String {
+    let distro_info = report.distro.as_ref().and_then(|s| s.as_ref().ok());
+
+    let os_release_default = OsRelease::default();
+    let os_release = report.os_release.as_ref().unwrap_or(&os_release_default);
+
+    let style = Style::new(options.color.enabled(), options.theme, os_release);
+
+    let mut boxes: Vec<Vec<(String, String)>> = Vec::new();
+
+    for module in &report.modules {
+        match module {
+            Module::Distro => {
+                if let Some(distro_info) = distro_info {
+                    boxes.push(distro_lines(distro_info, options));
+                }
+            }
+            Module::Board => {
+                if let Some(Ok(board_info)) = &report.board {
+                    boxes.push(board_lines(board_info, options));
+                }
+            }
+            Module::Packages => {
+                if let Some(Ok(packages_info)) = &report.packages {
+                    boxes.push(packages_lines(packages_info, options));
+                }
+            }
+            Module::Desktop => {
+                if let Some(Ok(desktop_info)) = &report.desktop {
+                    boxes.push(desktop_lines(desktop_info, options));
+                }
+            }
+            Module::Battery => {
+                if let Some(Ok(batteries)) = &report.batteries {
+                    for battery in batteries {
+                        boxes.push(battery_lines(battery, options));
+                    }
+                }
+            }
+            Module::Gpu => {
+                if let Some(Ok(gpus)) = &report.gpus {
+                    boxes.push(gpu_lines(gpus, options));
+                }
+            }
+            Module::Display => {
+                if let Some(Ok(displays)) = &report.displays {
+                    boxes.push(display_lines(displays, options));
+                }
+            }
+            Module::Memory => {
+                if let Some(Ok(memory_info)) = &report.memory {
+                    boxes.push(memory_lines(memory_info, options));
+                }
+            }
+            Module::Cpu => {
+                if let Some(Ok(cpu_info)) = &report.cpu {
+                    boxes.push(cpu_lines(cpu_info, options));
+                }
+            }
+            Module::Disk => {
+                if let Some(Ok(disk_info)) = &report.disk {
+                    boxes.push(disk_lines(disk_info, options));
+                }
+            }
+        }
+    }
+
+    let width = options.width.or_else(layout::terminal_width);
+
+    let logo_lines = options.logo.lines(os_release).filter(|logo_lines| {
+        width.is_none_or(|width| {
+            width.saturating_sub(logo::width(logo_lines) + 4) >= Layout::min_width(&boxes)
+        })
+    });
+
+    let available = width.map(|width| match &logo_lines {
+        Some(logo_lines) => width.saturating_sub(logo::width(logo_lines) + 4),
+        None => width,
+    });
+
+    let layout = Layout::new(&boxes, available);
+
+    let output: String = boxes
+        .iter()
+        .map(|lines| layout.render_box(lines, &style))
+        .collect();
+
+    match logo_lines {
+        Some(logo_lines) => logo::attach(&output, &logo_lines, &style),
+        None => output,
+    }
+}
+
+pub fn render_json(report: &Report) -> Result<String> {
+    serde_json::to_string_pretty(report).context("print_module: render_json - serialization error")
+}
+
+pub fn render(report: &Report, format: OutputFormat, options: &TextOptions) -> Result<String> {
+    match format {
+        OutputFormat::Text => Ok(render_text(report, options)),
+        OutputFormat::Json => render_json(report),
+    }
 }
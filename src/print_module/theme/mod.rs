@@ -0,0 +1,379 @@
+use std::env;
+use std::io::{self, IsTerminal};
+
+use anyhow::{Result, bail};
+use clap::ValueEnum;
+use serde::Deserialize;
+
+use crate::system::os_release::OsRelease;
+
+pub static NAMES: [&str; 5] = ["default", "mono", "ocean", "forest", "sunset"];
+
+static ANSI_COLORS: [Color; 8] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::White,
+];
+
+static ANSI_BRIGHT_COLORS: [Color; 8] = [
+    Color::BrightBlack,
+    Color::BrightRed,
+    Color::BrightGreen,
+    Color::BrightYellow,
+    Color::BrightBlue,
+    Color::BrightMagenta,
+    Color::BrightCyan,
+    Color::BrightWhite,
+];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorMode {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub enum Color {
+    #[default]
+    Default,
+    Accent,
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+    Fixed(u8),
+    Rgb(u8, u8, u8),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    pub border: Color,
+    pub label: Color,
+    pub value: Color,
+    pub logo: Color,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ThemeOverrides {
+    pub border: Option<Color>,
+    pub label: Option<Color>,
+    pub value: Option<Color>,
+    pub logo: Option<Color>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub enabled: bool,
+    pub theme: Theme,
+    pub accent: Color,
+}
+
+impl ColorMode {
+    pub fn enabled(&self) -> bool {
+        match self {
+            ColorMode::Always => true,
+            ColorMode::Never => false,
+            ColorMode::Auto => {
+                let no_color = env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+
+                !no_color && io::stdout().is_terminal()
+            }
+        }
+    }
+}
+
+impl TryFrom<String> for Color {
+    type Error = String;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        let color = match name.to_lowercase().replace(['-', ' '], "_").as_str() {
+            "default" | "none" => Color::Default,
+            "accent" => Color::Accent,
+            "black" => Color::Black,
+            "red" => Color::Red,
+            "green" => Color::Green,
+            "yellow" => Color::Yellow,
+            "blue" => Color::Blue,
+            "magenta" => Color::Magenta,
+            "cyan" => Color::Cyan,
+            "white" => Color::White,
+            "bright_black" | "gray" | "grey" => Color::BrightBlack,
+            "bright_red" => Color::BrightRed,
+            "bright_green" => Color::BrightGreen,
+            "bright_yellow" => Color::BrightYellow,
+            "bright_blue" => Color::BrightBlue,
+            "bright_magenta" => Color::BrightMagenta,
+            "bright_cyan" => Color::BrightCyan,
+            "bright_white" => Color::BrightWhite,
+            other => match other.parse::<u8>() {
+                Ok(index) => Color::Fixed(index),
+                Err(_) => return Err(format!("unknown color \"{}\"", name)),
+            },
+        };
+
+        Ok(color)
+    }
+}
+
+impl Color {
+    fn code(&self) -> Option<String> {
+        let code = match self {
+            Color::Default | Color::Accent => return None,
+            Color::Black => 30,
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::White => 37,
+            Color::BrightBlack => 90,
+            Color::BrightRed => 91,
+            Color::BrightGreen => 92,
+            Color::BrightYellow => 93,
+            Color::BrightBlue => 94,
+            Color::BrightMagenta => 95,
+            Color::BrightCyan => 96,
+            Color::BrightWhite => 97,
+            Color::Fixed(index) => return Some(format!("38;5;{}", index)),
+            Color::Rgb(r, g, b) => return Some(format!("38;2;{};{};{}", r, g, b)),
+        };
+
+        Some(code.to_string())
+    }
+
+    pub fn for_distro(os_release: &OsRelease) -> Color {
+        if let Some(color) = os_release.ansi_color.as_deref().and_then(Self::from_ansi) {
+            return color;
+        }
+
+        os_release
+            .ids()
+            .find_map(|id| match id {
+                "arch" => Some(Color::Cyan),
+                "debian" | "rhel" | "centos" => Some(Color::Red),
+                "ubuntu" => Some(Color::Fixed(208)),
+                "fedora" | "nixos" | "alpine" => Some(Color::Blue),
+                "manjaro" | "linuxmint" | "void" => Some(Color::Green),
+                "gentoo" => Some(Color::Magenta),
+                _ if id.starts_with("opensuse") || id == "suse" => Some(Color::Green),
+                _ => None,
+            })
+            .unwrap_or(Color::Yellow)
+    }
+
+    fn from_ansi(sgr: &str) -> Option<Color> {
+        let codes: Vec<u8> = sgr
+            .split(';')
+            .map(|code| code.trim().parse().ok())
+            .collect::<Option<_>>()?;
+
+        let mut color = None;
+        let mut rest = codes.as_slice();
+
+        while !rest.is_empty() {
+            rest = match rest {
+                [38, 5, index, rest @ ..] => {
+                    color = Some(Color::Fixed(*index));
+                    rest
+                }
+                [38, 2, r, g, b, rest @ ..] => {
+                    color = Some(Color::Rgb(*r, *g, *b));
+                    rest
+                }
+                [code @ 30..=37, rest @ ..] => {
+                    color = Some(ANSI_COLORS[(*code - 30) as usize]);
+                    rest
+                }
+                [code @ 90..=97, rest @ ..] => {
+                    color = Some(ANSI_BRIGHT_COLORS[(*code - 90) as usize]);
+                    rest
+                }
+                [_, rest @ ..] => rest,
+                [] => rest,
+            };
+        }
+
+        color
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            border: Color::Accent,
+            label: Color::Accent,
+            value: Color::Default,
+            logo: Color::Accent,
+        }
+    }
+}
+
+impl Theme {
+    pub fn from_name(name: &str) -> Result<Self> {
+        let theme = match name {
+            "default" => Theme::default(),
+            "mono" => Theme {
+                border: Color::Default,
+                label: Color::Default,
+                value: Color::Default,
+                logo: Color::Default,
+            },
+            "ocean" => Theme {
+                border: Color::Blue,
+                label: Color::BrightCyan,
+                value: Color::Default,
+                logo: Color::Blue,
+            },
+            "forest" => Theme {
+                border: Color::Green,
+                label: Color::BrightGreen,
+                value: Color::Default,
+                logo: Color::Green,
+            },
+            "sunset" => Theme {
+                border: Color::Red,
+                label: Color::Yellow,
+                value: Color::Default,
+                logo: Color::BrightRed,
+            },
+            _ => bail!(
+                "theme: Theme::from_name() - Unknown theme \"{}\" (available: {})",
+                name,
+                NAMES.join(", ")
+            ),
+        };
+
+        Ok(theme)
+    }
+
+    pub fn apply(&mut self, overrides: &ThemeOverrides) {
+        if let Some(color) = overrides.border {
+            self.border = color;
+        }
+        if let Some(color) = overrides.label {
+            self.label = color;
+        }
+        if let Some(color) = overrides.value {
+            self.value = color;
+        }
+        if let Some(color) = overrides.logo {
+            self.logo = color;
+        }
+    }
+}
+
+impl Style {
+    pub fn new(enabled: bool, theme: Theme, os_release: &OsRelease) -> Self {
+        Self {
+            enabled,
+            theme,
+            accent: Color::for_distro(os_release),
+        }
+    }
+
+    fn paint_with(&self, color: Color, bold: bool, text: &str) -> String {
+        if !self.enabled || text.is_empty() {
+            return text.to_string();
+        }
+
+        let color = match color {
+            Color::Accent => self.accent,
+            other => other,
+        };
+
+        let code = match (color.code(), bold) {
+            (Some(code), true) => format!("1;{}", code),
+            (Some(code), false) => code,
+            (None, true) => "1".to_string(),
+            (None, false) => return text.to_string(),
+        };
+
+        format!("\x1b[{}m{}\x1b[0m", code, text)
+    }
+
+    pub fn border(&self, text: &str) -> String {
+        self.paint_with(self.theme.border, false, text)
+    }
+
+    pub fn label(&self, text: &str) -> String {
+        self.paint_with(self.theme.label, true, text)
+    }
+
+    pub fn value(&self, text: &str) -> String {
+        self.paint_with(self.theme.value, false, text)
+    }
+
+    pub fn logo(&self, text: &str) -> String {
+        self.paint_with(self.theme.logo, true, text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn os_release(content: &str) -> OsRelease {
+        OsRelease::parse(content)
+    }
+
+    #[test]
+    fn accent_prefers_ansi_color() {
+        let fedora = os_release("ID=fedora\nANSI_COLOR=\"0;38;2;60;110;180\"\n");
+        assert_eq!(Color::for_distro(&fedora), Color::Rgb(60, 110, 180));
+
+        let arch = os_release("ID=arch\nANSI_COLOR=\"38;2;23;147;209\"\n");
+        assert_eq!(Color::for_distro(&arch), Color::Rgb(23, 147, 209));
+
+        let debian = os_release("ID=debian\nANSI_COLOR=\"1;31\"\n");
+        assert_eq!(Color::for_distro(&debian), Color::Red);
+
+        let custom = os_release("ID=custom\nANSI_COLOR=\"0;38;5;208\"\n");
+        assert_eq!(Color::for_distro(&custom), Color::Fixed(208));
+
+        let bright = os_release("ID=custom\nANSI_COLOR=\"96\"\n");
+        assert_eq!(Color::for_distro(&bright), Color::BrightCyan);
+    }
+
+    #[test]
+    fn accent_falls_back_to_distro_table() {
+        assert_eq!(
+            Color::for_distro(&os_release("ID=ubuntu\n")),
+            Color::Fixed(208)
+        );
+        assert_eq!(
+            Color::for_distro(&os_release("ID=pop\nID_LIKE=\"ubuntu debian\"\n")),
+            Color::Fixed(208)
+        );
+        assert_eq!(
+            Color::for_distro(&os_release("ID=gentoo\nANSI_COLOR=\"1\"\n")),
+            Color::Magenta
+        );
+        assert_eq!(
+            Color::for_distro(&os_release("ID=custom\nANSI_COLOR=\"bogus\"\n")),
+            Color::Yellow
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use crate::hardware::{
+    battery::BatteryInfo,
+    board::BoardInfo,
+    cpu::CpuInfo,
+    disk::{DiskInfo, DiskOptions},
+    display::DisplayInfo,
+    gpu::GpuInfo,
+    memory::MemoryInfo,
+};
+use crate::system::{
+    desktop::DesktopInfo, distro::DistroInfo, os_release::OsRelease, packages::PackagesInfo,
+};
+
+use anyhow::{Error, Result};
+use clap::ValueEnum;
+use serde::Deserialize;
+use serde::ser::{Serialize, SerializeMap, SerializeStruct, Serializer};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Module {
+    Distro,
+    Board,
+    Packages,
+    Desktop,
+    Battery,
+    Gpu,
+    Display,
+    Memory,
+    Cpu,
+    Disk,
+}
+
+#[derive(Debug, Default)]
+pub struct Report {
+    pub modules: Vec<Module>,
+    pub os_release: Option<OsRelease>,
+    pub distro: Option<Result<DistroInfo>>,
+    pub board: Option<Result<BoardInfo>>,
+    pub packages: Option<Result<PackagesInfo>>,
+    pub desktop: Option<Result<DesktopInfo>>,
+    pub batteries: Option<Result<Vec<BatteryInfo>>>,
+    pub gpus: Option<Result<Vec<GpuInfo>>>,
+    pub displays: Option<Result<Vec<DisplayInfo>>>,
+    pub memory: Option<Result<MemoryInfo>>,
+    pub cpu: Option<Result<CpuInfo>>,
+    pub disk: Option<Result<DiskInfo>>,
+}
+
+struct SectionError<'a>(&'a Error);
+
+struct Section<'a, T>(&'a Result<T>);
+
+impl Module {
+    pub const DEFAULT_ORDER: [Module; 10] = [
+        Module::Distro,
+        Module::Board,
+        Module::Packages,
+        Module::Desktop,
+        Module::Battery,
+        Module::Gpu,
+        Module::Display,
+        Module::Memory,
+        Module::Cpu,
+        Module::Disk,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Module::Distro => "distro",
+            Module::Board => "board",
+            Module::Packages => "packages",
+            Module::Desktop => "desktop",
+            Module::Battery => "battery",
+            Module::Gpu => "gpu",
+            Module::Display => "display",
+            Module::Memory => "memory",
+            Module::Cpu => "cpu",
+            Module::Disk => "disk",
+        }
+    }
+
+    pub fn resolve(modules: Option<&[Module]>, skip: &[Module]) -> Vec<Module> {
+        let mut resolved: Vec<Module> = Vec::new();
+
+        for module in modules.unwrap_or(&Self::DEFAULT_ORDER) {
+            if !skip.contains(module) && !resolved.contains(module) {
+                resolved.push(*module);
+            }
+        }
+
+        resolved
+    }
+}
+
+impl Report {
+    pub fn collect() -> Self {
+        Self::collect_modules(&Module::DEFAULT_ORDER, &DiskOptions::default())
+    }
+
+    pub fn collect_modules(modules: &[Module], disk_options: &DiskOptions) -> Self {
+        let os_release = OsRelease::new();
+
+        let mut report = Self {
+            modules: modules.to_vec(),
+            os_release: os_release.as_ref().ok().cloned(),
+            ..Self::default()
+        };
+
+        for module in modules {
+            match module {
+                Module::Distro => report.distro = Some(DistroInfo::new(&os_release)),
+                Module::Board => report.board = Some(BoardInfo::new()),
+                Module::Packages => report.packages = Some(PackagesInfo::new()),
+                Module::Desktop => report.desktop = Some(DesktopInfo::new()),
+                Module::Battery => report.batteries = Some(BatteryInfo::get_bats()),
+                Module::Gpu => report.gpus = Some(GpuInfo::get_gpus()),
+                Module::Display => report.displays = Some(DisplayInfo::get_displays()),
+                Module::Memory => report.memory = Some(MemoryInfo::new()),
+                Module::Cpu => report.cpu = Some(CpuInfo::new()),
+                Module::Disk => report.disk = Some(DiskInfo::new(disk_options)),
+            }
+        }
+
+        report
+    }
+
+    pub fn error(&self, module: Module) -> Option<&Error> {
+        match module {
+            Module::Distro => self.distro.as_ref()?.as_ref().err(),
+            Module::Board => self.board.as_ref()?.as_ref().err(),
+            Module::Packages => self.packages.as_ref()?.as_ref().err(),
+            Module::Desktop => self.desktop.as_ref()?.as_ref().err(),
+            Module::Battery => self.batteries.as_ref()?.as_ref().err(),
+            Module::Gpu => self.gpus.as_ref()?.as_ref().err(),
+            Module::Display => self.displays.as_ref()?.as_ref().err(),
+            Module::Memory => self.memory.as_ref()?.as_ref().err(),
+            Module::Cpu => self.cpu.as_ref()?.as_ref().err(),
+            Module::Disk => self.disk.as_ref()?.as_ref().err(),
+        }
+    }
+
+    pub fn errors(&self) -> Vec<&Error> {
+        self.modules
+            .iter()
+            .filter_map(|module| self.error(*module))
+            .collect()
+    }
+}
+
+impl Serialize for SectionError<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let causes: Vec<String> = self.0.chain().skip(1).map(|c| c.to_string()).collect();
+
+        let mut state = serializer.serialize_struct("SectionError", 2)?;
+        state.serialize_field("error", &self.0.to_string())?;
+        state.serialize_field("causes", &causes)?;
+        state.end()
+    }
+}
+
+impl<T: Serialize> Serialize for Section<'_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Ok(value) => value.serialize(serializer),
+            Err(error) => SectionError(error).serialize(serializer),
+        }
+    }
+}
+
+fn serialize_section<M: SerializeMap, T: Serialize>(
+    map: &mut M,
+    key: &str,
+    section: &Option<Result<T>>,
+) -> Result<(), M::Error> {
+    match section {
+        Some(result) => map.serialize_entry(key, &Section(result)),
+        None => Ok(()),
+    }
+}
+
+impl Serialize for Report {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+
+        for module in &self.modules {
+            let key = module.name();
+
+            match module {
+                Module::Distro => serialize_section(&mut map, key, &self.distro)?,
+                Module::Board => serialize_section(&mut map, key, &self.board)?,
+                Module::Packages => serialize_section(&mut map, key, &self.packages)?,
+                Module::Desktop => serialize_section(&mut map, key, &self.desktop)?,
+                Module::Battery => serialize_section(&mut map, key, &self.batteries)?,
+                Module::Gpu => serialize_section(&mut map, key, &self.gpus)?,
+                Module::Display => serialize_section(&mut map, key, &self.displays)?,
+                Module::Memory => serialize_section(&mut map, key, &self.memory)?,
+                Module::Cpu => serialize_section(&mut map, key, &self.cpu)?,
+                Module::Disk => serialize_section(&mut map, key, &self.disk)?,
+            }
+        }
+
+        map.end()
+    }
+}
@@ -0,0 +1,235 @@
+use std::env;
+use std::fs;
+use std::os::unix::fs::MetadataExt;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+static WINDOW_MANAGERS: &[(&str, &str)] = &[
+    ("kwin_wayland", "KWin"),
+    ("kwin_x11", "KWin"),
+    ("kwin", "KWin"),
+    ("gnome-shell", "Mutter"),
+    ("mutter", "Mutter"),
+    ("muffin", "Muffin"),
+    ("cinnamon", "Muffin"),
+    ("marco", "Marco"),
+    ("metacity", "Metacity"),
+    ("xfwm4", "Xfwm4"),
+    ("budgie-wm", "Budgie WM"),
+    ("cosmic-comp", "cosmic-comp"),
+    ("gala", "Gala"),
+    ("sway", "Sway"),
+    ("Hyprland", "Hyprland"),
+    ("hyprland", "Hyprland"),
+    ("river", "River"),
+    ("wayfire", "Wayfire"),
+    ("labwc", "labwc"),
+    ("niri", "niri"),
+    ("weston", "Weston"),
+    ("dwl", "dwl"),
+    ("hikari", "hikari"),
+    ("cage", "Cage"),
+    ("gamescope", "gamescope"),
+    ("i3", "i3"),
+    ("bspwm", "bspwm"),
+    ("awesome", "awesome"),
+    ("dwm", "dwm"),
+    ("herbstluftwm", "herbstluftwm"),
+    ("qtile", "Qtile"),
+    ("openbox", "Openbox"),
+    ("fluxbox", "Fluxbox"),
+    ("icewm", "IceWM"),
+    ("icewm-session", "IceWM"),
+    ("enlightenment", "Enlightenment"),
+    ("fvwm", "FVWM"),
+    ("fvwm3", "FVWM"),
+    ("spectrwm", "spectrwm"),
+    ("leftwm", "LeftWM"),
+    ("compiz", "Compiz"),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum SessionType {
+    Wayland,
+    X11,
+    Tty,
+    Unknow,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DesktopInfo {
+    pub desktop: Option<String>,
+    pub session: Option<String>,
+    pub window_manager: Option<String>,
+    pub session_type: SessionType,
+}
+
+impl DesktopInfo {
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            desktop: Self::get_desktop(),
+            session: Self::get_session(),
+            window_manager: Self::get_window_manager()?,
+            session_type: Self::get_session_type(),
+        })
+    }
+
+    fn get_desktop() -> Option<String> {
+        let current = non_empty_var("XDG_CURRENT_DESKTOP")
+            .or_else(|| non_empty_var("XDG_SESSION_DESKTOP"))
+            .or_else(|| non_empty_var("DESKTOP_SESSION"))?;
+
+        Some(desktop_from_list(&current))
+    }
+
+    fn get_session() -> Option<String> {
+        non_empty_var("DESKTOP_SESSION").or_else(|| non_empty_var("GDMSESSION"))
+    }
+
+    fn get_window_manager() -> Result<Option<String>> {
+        let proc = fs::read_dir("/proc")
+            .context("desktop: DesktopInfo::get_window_manager() - Error reading /proc")?;
+
+        let uid = unsafe { libc::getuid() };
+
+        for entry in proc.filter_map(|entry| entry.ok()) {
+            let file_name = entry.file_name();
+
+            if !file_name
+                .to_string_lossy()
+                .bytes()
+                .all(|b| b.is_ascii_digit())
+            {
+                continue;
+            }
+
+            let Ok(comm) = fs::read_to_string(entry.path().join("comm")) else {
+                continue;
+            };
+
+            if !entry.metadata().is_ok_and(|metadata| metadata.uid() == uid) {
+                continue;
+            }
+
+            if let Some(name) = window_manager_name(comm.trim()) {
+                return Ok(Some(name));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn get_session_type() -> SessionType {
+        match non_empty_var("XDG_SESSION_TYPE").as_deref() {
+            Some("wayland") => return SessionType::Wayland,
+            Some("x11") => return SessionType::X11,
+            Some("tty") => return SessionType::Tty,
+            _ => {}
+        }
+
+        if non_empty_var("WAYLAND_DISPLAY").is_some() {
+            SessionType::Wayland
+        } else if non_empty_var("DISPLAY").is_some() {
+            SessionType::X11
+        } else if fs::read_link("/proc/self/fd/0")
+            .is_ok_and(|path| is_virtual_console(&path.to_string_lossy()))
+        {
+            SessionType::Tty
+        } else {
+            SessionType::Unknow
+        }
+    }
+}
+
+fn desktop_from_list(current: &str) -> String {
+    let entries: Vec<&str> = current
+        .split(':')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .collect();
+
+    entries
+        .iter()
+        .find_map(|entry| known_desktop(entry))
+        .map(String::from)
+        .unwrap_or_else(|| entries.first().copied().unwrap_or(current).to_string())
+}
+
+fn known_desktop(desktop: &str) -> Option<&'static str> {
+    let name = match desktop.to_lowercase().as_str() {
+        "kde" | "plasma" | "plasmawayland" | "plasmax11" => "KDE Plasma",
+        "gnome" | "gnome-xorg" | "gnome-wayland" => "GNOME",
+        "x-cinnamon" | "cinnamon" => "Cinnamon",
+        "xfce" | "xfce4" => "Xfce",
+        "mate" => "MATE",
+        "lxqt" => "LXQt",
+        "lxde" => "LXDE",
+        "budgie" | "budgie-desktop" => "Budgie",
+        "pantheon" => "Pantheon",
+        "cosmic" => "COSMIC",
+        "deepin" => "Deepin",
+        "unity" => "Unity",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+fn window_manager_name(comm: &str) -> Option<String> {
+    if let Some((_, name)) = WINDOW_MANAGERS.iter().find(|(process, _)| *process == comm) {
+        return Some(name.to_string());
+    }
+
+    comm.starts_with("xmonad").then(|| "xmonad".to_string())
+}
+
+fn is_virtual_console(path: &str) -> bool {
+    path.strip_prefix("/dev/tty")
+        .is_some_and(|number| !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()))
+}
+
+fn non_empty_var(key: &str) -> Option<String> {
+    env::var(key).ok().filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn desktop_prefers_first_known_entry() {
+        assert_eq!(desktop_from_list("Budgie:GNOME"), "Budgie");
+        assert_eq!(desktop_from_list("Unity:Unity7:ubuntu"), "Unity");
+        assert_eq!(desktop_from_list("ubuntu:GNOME"), "GNOME");
+        assert_eq!(desktop_from_list("X-Cinnamon"), "Cinnamon");
+    }
+
+    #[test]
+    fn desktop_falls_back_to_first_entry() {
+        assert_eq!(desktop_from_list("Hyprland"), "Hyprland");
+        assert_eq!(desktop_from_list("sway:wlroots"), "sway");
+    }
+
+    #[test]
+    fn virtual_consoles() {
+        assert!(is_virtual_console("/dev/tty1"));
+        assert!(is_virtual_console("/dev/tty12"));
+        assert!(!is_virtual_console("/dev/tty"));
+        assert!(!is_virtual_console("/dev/ttyS0"));
+        assert!(!is_virtual_console("/dev/pts/3"));
+    }
+
+    #[test]
+    fn window_manager_names() {
+        assert_eq!(
+            window_manager_name("gnome-shell").as_deref(),
+            Some("Mutter")
+        );
+        assert_eq!(
+            window_manager_name("xmonad-x86_64-linux").as_deref(),
+            Some("xmonad")
+        );
+        assert_eq!(window_manager_name("bash"), None);
+    }
+}
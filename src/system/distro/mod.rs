@@ -1,9 +1,5 @@
 use std::env;
 use std::fs;
-use std::fs::File;
-
-use std::io::BufRead;
-use std::io::BufReader;
 use std::process::Command;
 
 use std::os::unix::fs::MetadataExt;
@@ -12,27 +8,29 @@ use std::time::UNIX_EPOCH;
 
 use chrono;
 
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
+use serde::Serialize;
+
+use crate::system::os_release::OsRelease;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct KernelInfo {
     pub name: String,
     pub version: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct UptimeInfo {
     pub hours: u64,
     pub minutes: u64,
     pub date_installation: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct DistroInfo {
-    pub name: String,
+    pub os_release: OsRelease,
     pub arch: String,
     pub shell: String,
-    pub build_id: String,
     pub uptime_info: UptimeInfo,
     pub kernel_info: KernelInfo,
 }
@@ -74,7 +72,7 @@ impl UptimeInfo {
     fn get_date_installation() -> Option<String> {
         let paths_to_check = ["/", "/etc", "/var/log", "/root"];
 
-        let mut oldest_time = std::u64::MAX;
+        let mut oldest_time = u64::MAX;
         //let mut oldest_path = "";
 
         for path_str in &paths_to_check {
@@ -89,7 +87,7 @@ impl UptimeInfo {
             }
         }
 
-        if oldest_time == std::u64::MAX {
+        if oldest_time == u64::MAX {
             return None;
         }
         Some(Self::format_timestamp(oldest_time))
@@ -116,8 +114,11 @@ impl KernelInfo {
 }
 
 impl DistroInfo {
-    pub fn new() -> Result<Self> {
-        let (name, build_id) = Self::parse_os_release()?;
+    pub fn new(os_release: &Result<OsRelease>) -> Result<Self> {
+        let os_release = match os_release {
+            Ok(os_release) => os_release.clone(),
+            Err(error) => bail!("distro: DistroInfo::new() - {:#}", error),
+        };
         let arch: String = Self::get_arch()?;
         let shell: String = Self::get_shell()?;
 
@@ -126,37 +127,14 @@ impl DistroInfo {
         let kernel_info: KernelInfo = KernelInfo::new()?;
 
         Ok(Self {
-            name,
+            os_release,
             arch,
             shell,
-            build_id,
             uptime_info,
             kernel_info,
         })
     }
 
-    fn parse_os_release() -> Result<(String, String)> {
-        let file = File::open("/etc/os-release")
-            .context("distro: DistroInfo::parse_os_release() - Error opening file")?;
-        let reader = BufReader::new(file);
-
-        let mut distro_name = String::new();
-        let mut build_id = String::new();
-
-        for line in reader.lines().map_while(Result::ok) {
-            if line.starts_with("NAME=") {
-                distro_name = line["NAME=".len()..].trim_matches('"').to_string();
-            } else if line.starts_with("BUILD_ID=") {
-                build_id = line["BUILD_ID=".len()..].to_string();
-            }
-
-            if !distro_name.is_empty() && !build_id.is_empty() {
-                break;
-            }
-        }
-
-        Ok((distro_name, build_id))
-    }
     /*
         fn parse_line(line: &str) -> String {
             line.split_whitespace().nth(0).unwrap().to_string()
@@ -175,7 +153,6 @@ impl DistroInfo {
     }
 
     fn get_shell() -> Result<String> {
-        Ok(env::var("SHELL")
-            .context("distro: DistroInfo::get_shell() - Error getting variable $SHELL")?)
+        env::var("SHELL").context("distro: DistroInfo::get_shell() - Error getting variable $SHELL")
     }
 }
@@ -1 +1,4 @@
+pub mod desktop;
 pub mod distro;
+pub mod os_release;
+pub mod packages;
@@ -0,0 +1,243 @@
+use std::collections::BTreeMap;
+use std::fs;
+
+use anyhow::{Result, bail};
+use serde::Serialize;
+
+static OS_RELEASE_PATHS: [&str; 2] = ["/etc/os-release", "/usr/lib/os-release"];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OsRelease {
+    pub name: String,
+    pub pretty_name: String,
+    pub id: String,
+    pub id_like: Vec<String>,
+    pub version: Option<String>,
+    pub version_id: Option<String>,
+    pub version_codename: Option<String>,
+    pub variant: Option<String>,
+    pub variant_id: Option<String>,
+    pub build_id: Option<String>,
+    pub image_id: Option<String>,
+    pub image_version: Option<String>,
+    pub ansi_color: Option<String>,
+    pub logo: Option<String>,
+    pub cpe_name: Option<String>,
+    pub home_url: Option<String>,
+    pub documentation_url: Option<String>,
+    pub support_url: Option<String>,
+    pub bug_report_url: Option<String>,
+    pub privacy_policy_url: Option<String>,
+    pub extra: BTreeMap<String, String>,
+}
+
+impl Default for OsRelease {
+    fn default() -> Self {
+        Self {
+            name: "Linux".to_string(),
+            pretty_name: "Linux".to_string(),
+            id: "linux".to_string(),
+            id_like: Vec::new(),
+            version: None,
+            version_id: None,
+            version_codename: None,
+            variant: None,
+            variant_id: None,
+            build_id: None,
+            image_id: None,
+            image_version: None,
+            ansi_color: None,
+            logo: None,
+            cpe_name: None,
+            home_url: None,
+            documentation_url: None,
+            support_url: None,
+            bug_report_url: None,
+            privacy_policy_url: None,
+            extra: BTreeMap::new(),
+        }
+    }
+}
+
+impl OsRelease {
+    pub fn new() -> Result<Self> {
+        for path in OS_RELEASE_PATHS {
+            if let Ok(content) = fs::read_to_string(path) {
+                return Ok(Self::parse(&content));
+            }
+        }
+
+        bail!(
+            "os_release: OsRelease::new() - Error reading {}",
+            OS_RELEASE_PATHS.join(" and ")
+        )
+    }
+
+    pub fn parse(content: &str) -> Self {
+        let mut os_release = Self::default();
+
+        for line in content.lines() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let Some((key, raw_value)) = line.split_once('=') else {
+                continue;
+            };
+
+            let Some(value) = unquote(raw_value) else {
+                continue;
+            };
+
+            os_release.set(key.trim(), value);
+        }
+
+        os_release
+    }
+
+    fn set(&mut self, key: &str, value: String) {
+        let optional = Some(value.clone());
+
+        match key {
+            "NAME" => self.name = value,
+            "PRETTY_NAME" => self.pretty_name = value,
+            "ID" => self.id = value,
+            "ID_LIKE" => self.id_like = value.split_whitespace().map(String::from).collect(),
+            "VERSION" => self.version = optional,
+            "VERSION_ID" => self.version_id = optional,
+            "VERSION_CODENAME" => self.version_codename = optional,
+            "VARIANT" => self.variant = optional,
+            "VARIANT_ID" => self.variant_id = optional,
+            "BUILD_ID" => self.build_id = optional,
+            "IMAGE_ID" => self.image_id = optional,
+            "IMAGE_VERSION" => self.image_version = optional,
+            "ANSI_COLOR" => self.ansi_color = optional,
+            "LOGO" => self.logo = optional,
+            "CPE_NAME" => self.cpe_name = optional,
+            "HOME_URL" => self.home_url = optional,
+            "DOCUMENTATION_URL" => self.documentation_url = optional,
+            "SUPPORT_URL" => self.support_url = optional,
+            "BUG_REPORT_URL" => self.bug_report_url = optional,
+            "PRIVACY_POLICY_URL" => self.privacy_policy_url = optional,
+            _ => {
+                self.extra.insert(key.to_string(), value);
+            }
+        }
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.id.as_str()).chain(self.id_like.iter().map(String::as_str))
+    }
+
+    pub fn display_name(&self) -> String {
+        let version = self.version_id.as_ref().or(self.build_id.as_ref());
+
+        match version {
+            Some(version) if !self.pretty_name.contains(version.as_str()) => {
+                format!("{} {}", self.pretty_name, version)
+            }
+            _ => self.pretty_name.clone(),
+        }
+    }
+}
+
+fn unquote(raw: &str) -> Option<String> {
+    let mut value = String::new();
+    let mut chars = raw.trim().chars();
+    let mut quote: Option<char> = None;
+
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(open), _) if c == open => quote = None,
+            (None, '\\') => value.push(chars.next()?),
+            (Some('"'), '\\') => match chars.next()? {
+                escaped @ ('$' | '"' | '\\' | '`') => value.push(escaped),
+                other => {
+                    value.push('\\');
+                    value.push(other);
+                }
+            },
+            _ => value.push(c),
+        }
+    }
+
+    match quote {
+        Some(_) => None,
+        None => Some(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_quotes_are_literal() {
+        let os_release = OsRelease::parse(r#"NAME='Foo \"Linux\" $HOME'"#);
+
+        assert_eq!(os_release.name, r#"Foo \"Linux\" $HOME"#);
+    }
+
+    #[test]
+    fn double_quotes_handle_escapes() {
+        let os_release = OsRelease::parse(r#"PRETTY_NAME="Foo \"Rolling\" \\ \$5 \`x\` \n""#);
+
+        assert_eq!(os_release.pretty_name, r#"Foo "Rolling" \ $5 `x` \n"#);
+    }
+
+    #[test]
+    fn unquoted_backslash_escapes_next_character() {
+        let os_release = OsRelease::parse(r"NAME=Foo\ Linux\\");
+
+        assert_eq!(os_release.name, r"Foo Linux\");
+    }
+
+    #[test]
+    fn unterminated_quote_skips_line() {
+        let os_release = OsRelease::parse("NAME=\"Broken\nID=foo\nVERSION_ID='1.0");
+
+        assert_eq!(os_release.name, "Linux");
+        assert_eq!(os_release.id, "foo");
+        assert_eq!(os_release.version_id, None);
+    }
+
+    #[test]
+    fn comments_blank_and_unknown_lines() {
+        let os_release = OsRelease::parse("# NAME=Commented\n\n  \nGARBAGE\nFOO_EXTRA=bar\n");
+
+        assert_eq!(os_release.name, "Linux");
+        assert_eq!(
+            os_release.extra.get("FOO_EXTRA").map(String::as_str),
+            Some("bar")
+        );
+    }
+
+    #[test]
+    fn id_like_is_split_on_whitespace() {
+        let os_release = OsRelease::parse("ID=linuxmint\nID_LIKE=\"ubuntu  debian\"");
+
+        assert_eq!(os_release.id_like, ["ubuntu", "debian"]);
+        assert_eq!(
+            os_release.ids().collect::<Vec<_>>(),
+            ["linuxmint", "ubuntu", "debian"]
+        );
+    }
+
+    #[test]
+    fn display_name_appends_missing_version() {
+        let os_release = OsRelease::parse("PRETTY_NAME=\"Arch Linux\"\nBUILD_ID=rolling");
+        assert_eq!(os_release.display_name(), "Arch Linux rolling");
+
+        let os_release = OsRelease::parse("PRETTY_NAME=\"Fedora Linux 40\"\nVERSION_ID=40");
+        assert_eq!(os_release.display_name(), "Fedora Linux 40");
+
+        let os_release =
+            OsRelease::parse("PRETTY_NAME=\"Debian\"\nVERSION_ID=12\nBUILD_ID=20240101");
+        assert_eq!(os_release.display_name(), "Debian 12");
+
+        assert_eq!(OsRelease::parse("").display_name(), "Linux");
+    }
+}
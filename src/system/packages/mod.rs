@@ -0,0 +1,615 @@
+use std::collections::HashSet;
+use std::env;
+use std::fs::{self, File};
+use std::os::unix::fs::FileExt;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Result, bail};
+use serde::Serialize;
+
+static NDB_PAGE_SIZE: usize = 4096;
+static NDB_SLOT_SIZE: usize = 16;
+
+#[derive(Debug, Serialize)]
+pub struct PackageCount {
+    pub manager: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PackagesInfo {
+    pub managers: Vec<PackageCount>,
+}
+
+impl PackagesInfo {
+    pub fn new() -> Result<Self> {
+        let home = env::var_os("HOME").map(PathBuf::from);
+        let mut managers: Vec<PackageCount> = Vec::new();
+
+        let mut push = |manager: &str, count: Option<usize>| {
+            if let Some(count) = count.filter(|count| *count > 0) {
+                managers.push(PackageCount {
+                    manager: manager.to_string(),
+                    count,
+                });
+            }
+        };
+
+        push("pacman", count_pacman());
+        push("dpkg", count_dpkg());
+        push("rpm", count_rpm());
+        push("apk", count_apk());
+        push("xbps", count_xbps());
+        push(
+            "nix-system",
+            count_nix_profile(Path::new("/run/current-system/sw")),
+        );
+        push(
+            "nix-default",
+            count_nix_profile(Path::new("/nix/var/nix/profiles/default")),
+        );
+        push(
+            "nix-user",
+            home.as_ref()
+                .and_then(|home| count_nix_profile(&home.join(".nix-profile"))),
+        );
+        push(
+            "flatpak-system",
+            count_flatpak(Path::new("/var/lib/flatpak")),
+        );
+        push(
+            "flatpak-user",
+            home.as_ref()
+                .and_then(|home| count_flatpak(&home.join(".local/share/flatpak"))),
+        );
+        push("snap", count_snap());
+
+        if managers.is_empty() {
+            bail!("packages: PackagesInfo::new() - No supported package database found");
+        }
+
+        Ok(Self { managers })
+    }
+}
+
+fn count_dirs(path: &Path) -> Option<usize> {
+    let entries = fs::read_dir(path).ok()?;
+
+    Some(
+        entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+            .count(),
+    )
+}
+
+fn count_pacman() -> Option<usize> {
+    count_dirs(Path::new("/var/lib/pacman/local"))
+}
+
+fn count_dpkg() -> Option<usize> {
+    let status = fs::read_to_string("/var/lib/dpkg/status").ok()?;
+
+    Some(
+        status
+            .lines()
+            .filter_map(|line| line.strip_prefix("Status:"))
+            .filter(|value| value.split_whitespace().last() == Some("installed"))
+            .count(),
+    )
+}
+
+fn count_rpm() -> Option<usize> {
+    [
+        "/var/lib/rpm/rpmdb.sqlite",
+        "/usr/lib/sysimage/rpm/rpmdb.sqlite",
+    ]
+    .iter()
+    .find_map(|path| count_sqlite_rows(Path::new(path), "Packages"))
+    .or_else(|| {
+        [
+            "/usr/lib/sysimage/rpm/Packages.db",
+            "/var/lib/rpm/Packages.db",
+        ]
+        .iter()
+        .find_map(|path| count_ndb_packages(Path::new(path)))
+    })
+}
+
+fn count_ndb_packages(path: &Path) -> Option<usize> {
+    let file = File::open(path).ok()?;
+
+    let mut header = [0u8; NDB_SLOT_SIZE * 2];
+    file.read_exact_at(&mut header, 0).ok()?;
+
+    if header[0..4] != *b"RpmP" {
+        return None;
+    }
+
+    let slot_pages = u32::from_le_bytes(header[12..16].try_into().ok()?) as usize;
+    let file_size = file.metadata().ok()?.len();
+    let slots_size = slot_pages
+        .checked_mul(NDB_PAGE_SIZE)
+        .filter(|size| *size as u64 <= file_size)?;
+
+    let mut slots = vec![0u8; slots_size];
+    file.read_exact_at(&mut slots, 0).ok()?;
+
+    Some(
+        slots
+            .chunks_exact(NDB_SLOT_SIZE)
+            .skip(2)
+            .filter(|slot| slot[0..4] == *b"Slot" && slot[4..8] != [0, 0, 0, 0])
+            .count(),
+    )
+}
+
+fn count_apk() -> Option<usize> {
+    let installed = fs::read_to_string("/lib/apk/db/installed").ok()?;
+
+    Some(
+        installed
+            .lines()
+            .filter(|line| line.starts_with("P:"))
+            .count(),
+    )
+}
+
+fn count_xbps() -> Option<usize> {
+    let entries = fs::read_dir("/var/db/xbps").ok()?;
+
+    let pkgdb = entries.filter_map(|entry| entry.ok()).find(|entry| {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        name.starts_with("pkgdb-") && name.ends_with(".plist")
+    })?;
+
+    let content = fs::read_to_string(pkgdb.path()).ok()?;
+
+    Some(content.matches("<key>pkgver</key>").count())
+}
+
+fn count_nix_profile(profile: &Path) -> Option<usize> {
+    if let Ok(manifest) = fs::read_to_string(profile.join("manifest.json")) {
+        let manifest: serde_json::Value = serde_json::from_str(&manifest).ok()?;
+
+        return match &manifest["elements"] {
+            serde_json::Value::Array(elements) => Some(elements.len()),
+            serde_json::Value::Object(elements) => Some(elements.len()),
+            _ => None,
+        };
+    }
+
+    let entries = fs::read_dir(profile.join("bin")).ok()?;
+
+    let store_paths: HashSet<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| fs::read_link(entry.path()).ok())
+        .filter(|target| target.starts_with("/nix/store"))
+        .map(|target| target.components().take(4).collect::<PathBuf>())
+        .collect();
+
+    Some(store_paths.len())
+}
+
+fn count_flatpak(root: &Path) -> Option<usize> {
+    let apps = count_dirs(&root.join("app"));
+    let runtimes = count_dirs(&root.join("runtime"));
+
+    match (apps, runtimes) {
+        (None, None) => None,
+        (apps, runtimes) => Some(apps.unwrap_or(0) + runtimes.unwrap_or(0)),
+    }
+}
+
+fn count_snap() -> Option<usize> {
+    let entries = fs::read_dir("/snap").ok()?;
+
+    Some(
+        entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+            .filter(|entry| entry.file_name() != "bin")
+            .count(),
+    )
+}
+
+struct SqliteFile {
+    file: File,
+    page_size: usize,
+    usable_size: usize,
+}
+
+fn count_sqlite_rows(path: &Path, table: &str) -> Option<usize> {
+    let db = SqliteFile::open(path)?;
+    let root_page = db.table_root_page(table)?;
+
+    db.count_rows(root_page, 0)
+}
+
+impl SqliteFile {
+    fn open(path: &Path) -> Option<Self> {
+        let file = File::open(path).ok()?;
+
+        let mut header = [0u8; 100];
+        file.read_exact_at(&mut header, 0).ok()?;
+
+        if !header.starts_with(b"SQLite format 3\0") {
+            return None;
+        }
+
+        let page_size = match u16::from_be_bytes([header[16], header[17]]) {
+            1 => 65536,
+            size => size as usize,
+        };
+
+        if !page_size.is_power_of_two() || !(512..=65536).contains(&page_size) {
+            return None;
+        }
+
+        let usable_size = page_size - header[20] as usize;
+
+        if usable_size < 480 {
+            return None;
+        }
+
+        Some(Self {
+            file,
+            page_size,
+            usable_size,
+        })
+    }
+
+    fn page(&self, number: usize) -> Option<Vec<u8>> {
+        let start = number.checked_sub(1)? * self.page_size;
+        let mut page = vec![0u8; self.page_size];
+
+        self.file.read_exact_at(&mut page, start as u64).ok()?;
+
+        Some(page)
+    }
+
+    fn cells(page: &[u8], number: usize) -> Option<(u8, Vec<usize>, Option<usize>)> {
+        let header = if number == 1 { 100 } else { 0 };
+
+        let page_type = *page.get(header)?;
+        let cell_count = u16::from_be_bytes([*page.get(header + 3)?, *page.get(header + 4)?]);
+
+        let (right_child, pointers_start) = match page_type {
+            0x05 => (
+                Some(
+                    u32::from_be_bytes(page.get(header + 8..header + 12)?.try_into().ok()?)
+                        as usize,
+                ),
+                header + 12,
+            ),
+            0x0d => (None, header + 8),
+            _ => return None,
+        };
+
+        let mut offsets = Vec::with_capacity(cell_count as usize);
+
+        for i in 0..cell_count as usize {
+            let at = pointers_start + i * 2;
+            offsets.push(u16::from_be_bytes([*page.get(at)?, *page.get(at + 1)?]) as usize);
+        }
+
+        Some((page_type, offsets, right_child))
+    }
+
+    fn count_rows(&self, number: usize, depth: usize) -> Option<usize> {
+        if depth > 32 {
+            return None;
+        }
+
+        let page = self.page(number)?;
+        let (page_type, offsets, right_child) = Self::cells(&page, number)?;
+
+        if page_type == 0x0d {
+            return Some(offsets.len());
+        }
+
+        let mut rows = 0;
+
+        for offset in offsets {
+            let child = u32::from_be_bytes(page.get(offset..offset + 4)?.try_into().ok()?);
+            rows += self.count_rows(child as usize, depth + 1)?;
+        }
+
+        rows += self.count_rows(right_child?, depth + 1)?;
+
+        Some(rows)
+    }
+
+    fn table_root_page(&self, table: &str) -> Option<usize> {
+        self.find_table(1, table, 0)
+    }
+
+    fn find_table(&self, number: usize, table: &str, depth: usize) -> Option<usize> {
+        if depth > 32 {
+            return None;
+        }
+
+        let page = self.page(number)?;
+        let (page_type, offsets, right_child) = Self::cells(&page, number)?;
+
+        if page_type == 0x05 {
+            let children = offsets
+                .iter()
+                .filter_map(|offset| page.get(*offset..*offset + 4))
+                .filter_map(|bytes| bytes.try_into().ok().map(u32::from_be_bytes))
+                .map(|child| child as usize)
+                .chain(right_child);
+
+            for child in children {
+                if let Some(root) = self.find_table(child, table, depth + 1) {
+                    return Some(root);
+                }
+            }
+
+            return None;
+        }
+
+        offsets
+            .into_iter()
+            .find_map(|offset| self.schema_entry(&page, offset, table))
+    }
+
+    fn schema_entry(&self, page: &[u8], offset: usize, table: &str) -> Option<usize> {
+        let (payload_size, read) = varint(page.get(offset..)?)?;
+        let (_rowid, read_rowid) = varint(page.get(offset + read..)?)?;
+
+        let start = offset + read + read_rowid;
+        let payload = self.payload(page, start, payload_size as usize)?;
+
+        let values = record_values(&payload, 4)?;
+
+        let is_table = values[0] == RecordValue::Text(b"table");
+        let name_matches = values[1] == RecordValue::Text(table.as_bytes());
+
+        match (is_table && name_matches, &values[3]) {
+            (true, RecordValue::Integer(root)) => Some(*root as usize),
+            _ => None,
+        }
+    }
+
+    fn payload(&self, page: &[u8], start: usize, payload_size: usize) -> Option<Vec<u8>> {
+        let max_local = self.usable_size - 35;
+
+        let local_size = match payload_size <= max_local {
+            true => payload_size,
+            false => {
+                let min_local = (self.usable_size - 12) * 32 / 255 - 23;
+                let local = min_local + (payload_size - min_local) % (self.usable_size - 4);
+
+                if local <= max_local { local } else { min_local }
+            }
+        };
+
+        let mut payload = page.get(start..start + local_size)?.to_vec();
+        let mut next = match local_size < payload_size {
+            true => u32::from_be_bytes(
+                page.get(start + local_size..start + local_size + 4)?
+                    .try_into()
+                    .ok()?,
+            ) as usize,
+            false => 0,
+        };
+
+        let max_pages = (payload_size - local_size).div_ceil(self.usable_size - 4);
+
+        for _ in 0..max_pages {
+            let overflow = self.page(next)?;
+            let remaining = payload_size - payload.len();
+            let content = overflow.get(4..4 + remaining.min(self.usable_size - 4))?;
+
+            payload.extend_from_slice(content);
+            next = u32::from_be_bytes(overflow.get(0..4)?.try_into().ok()?) as usize;
+        }
+
+        match payload.len() == payload_size {
+            true => Some(payload),
+            false => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum RecordValue<'a> {
+    Null,
+    Integer(i64),
+    Text(&'a [u8]),
+    Other,
+}
+
+fn record_values(payload: &[u8], columns: usize) -> Option<Vec<RecordValue<'_>>> {
+    let (header_size, mut header_at) = varint(payload)?;
+    let mut body_at = header_size as usize;
+    let mut values = Vec::with_capacity(columns);
+
+    while values.len() < columns && header_at < header_size as usize {
+        let (serial_type, read) = varint(payload.get(header_at..)?)?;
+        header_at += read;
+
+        let size = match serial_type {
+            0 | 8 | 9 => 0,
+            1..=4 => serial_type as usize,
+            5 => 6,
+            6 | 7 => 8,
+            n if n >= 12 => (n as usize - 12) / 2,
+            _ => return None,
+        };
+
+        let bytes = payload.get(body_at..body_at + size)?;
+        body_at += size;
+
+        values.push(match serial_type {
+            0 => RecordValue::Null,
+            8 => RecordValue::Integer(0),
+            9 => RecordValue::Integer(1),
+            1..=6 => {
+                let mut number: i64 = if bytes[0] & 0x80 != 0 { -1 } else { 0 };
+                for byte in bytes {
+                    number = (number << 8) | *byte as i64;
+                }
+                RecordValue::Integer(number)
+            }
+            n if n >= 13 && n % 2 == 1 => RecordValue::Text(bytes),
+            _ => RecordValue::Other,
+        });
+    }
+
+    if values.len() < columns {
+        return None;
+    }
+
+    Some(values)
+}
+
+fn varint(bytes: &[u8]) -> Option<(u64, usize)> {
+    let mut value: u64 = 0;
+
+    for (i, byte) in bytes.iter().take(9).enumerate() {
+        if i == 8 {
+            return Some(((value << 8) | *byte as u64, 9));
+        }
+
+        value = (value << 7) | (*byte & 0x7f) as u64;
+
+        if byte & 0x80 == 0 {
+            return Some((value, i + 1));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/packages")
+            .join(name)
+    }
+
+    #[test]
+    fn sqlite_single_leaf_with_overflow() {
+        assert_eq!(
+            count_sqlite_rows(&fixture("rpmdb-5.sqlite"), "Packages"),
+            Some(5)
+        );
+    }
+
+    #[test]
+    fn sqlite_deep_tree_and_multi_page_schema() {
+        assert_eq!(
+            count_sqlite_rows(&fixture("rpmdb-5000.sqlite"), "Packages"),
+            Some(5000)
+        );
+        assert_eq!(
+            count_sqlite_rows(&fixture("rpmdb-5000.sqlite"), "Name"),
+            Some(50)
+        );
+    }
+
+    #[test]
+    fn sqlite_large_pages() {
+        assert_eq!(
+            count_sqlite_rows(&fixture("rpmdb-20000.sqlite"), "Packages"),
+            Some(20000)
+        );
+    }
+
+    #[test]
+    fn sqlite_missing_table_or_file() {
+        assert_eq!(
+            count_sqlite_rows(&fixture("rpmdb-5.sqlite"), "Missing"),
+            None
+        );
+        assert_eq!(
+            count_sqlite_rows(&fixture("missing.sqlite"), "Packages"),
+            None
+        );
+        assert_eq!(
+            count_sqlite_rows(
+                Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("Cargo.toml")
+                    .as_path(),
+                "Packages"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn sqlite_schema_entries_with_overflow_pages() {
+        assert_eq!(
+            count_sqlite_rows(&fixture("rpmdb-long-schema.sqlite"), "Packages"),
+            Some(7)
+        );
+        assert_eq!(
+            count_sqlite_rows(&fixture("rpmdb-long-schema.sqlite"), "Basenames"),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn sqlite_rejects_bad_page_sizes() {
+        let original = fs::read(fixture("rpmdb-5.sqlite")).unwrap();
+
+        for (page_size, reserved) in [(0u16, 0u8), (0x0300, 0), (256, 0), (512, 40)] {
+            let mut data = original.clone();
+            data[16..18].copy_from_slice(&page_size.to_be_bytes());
+            data[20] = reserved;
+
+            let path = env::temp_dir().join(format!(
+                "atlasfetch-sqlite-{}-{}-{}.db",
+                std::process::id(),
+                page_size,
+                reserved
+            ));
+            fs::write(&path, &data).unwrap();
+
+            let count = count_sqlite_rows(&path, "Packages");
+            fs::remove_file(&path).unwrap();
+
+            assert_eq!(count, None);
+        }
+    }
+
+    #[test]
+    fn varint_encodings() {
+        assert_eq!(varint(&[0x05]), Some((5, 1)));
+        assert_eq!(varint(&[0x81, 0x00]), Some((128, 2)));
+        assert_eq!(varint(&[0xff; 9]), Some((u64::MAX, 9)));
+        assert_eq!(varint(&[0x81]), None);
+    }
+
+    #[test]
+    fn ndb_counts_used_slots() {
+        let mut data = vec![0u8; NDB_PAGE_SIZE * 2];
+        data[0..4].copy_from_slice(b"RpmP");
+        data[12..16].copy_from_slice(&1u32.to_le_bytes());
+
+        for (slot, package) in [(2, 1u32), (3, 0), (4, 7), (255, 9)] {
+            let at = slot * NDB_SLOT_SIZE;
+            data[at..at + 4].copy_from_slice(b"Slot");
+            data[at + 4..at + 8].copy_from_slice(&package.to_le_bytes());
+        }
+
+        let path = env::temp_dir().join(format!("atlasfetch-ndb-{}.db", std::process::id()));
+        fs::write(&path, &data).unwrap();
+
+        let count = count_ndb_packages(&path);
+        data[0] = b'X';
+        fs::write(&path, &data).unwrap();
+        let bad_magic = count_ndb_packages(&path);
+
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(count, Some(3));
+        assert_eq!(bad_magic, None);
+    }
+}